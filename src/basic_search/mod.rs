@@ -51,10 +51,10 @@ impl BasicSearch {
             let mut children = search_entry_element.child_elements();
 
             search_entry.internal_order_id = BasicSearch::get_prop(children.nth(0), |value| {
-                Some(u32::from_str(&value).map_err(|err| err.kind().clone().into()))
+                Some(u32::from_str(&value).map_err(|err| (*err.kind()).into()))
             });
             search_entry.order_id = BasicSearch::get_prop(children.nth(0), |value| {
-                Some(u32::from_str(&value).map_err(|err| err.kind().clone().into()))
+                Some(u32::from_str(&value).map_err(|err| (*err.kind()).into()))
             });
             let city = BasicSearch::get_text(children.nth(0));
             let street = BasicSearch::get_text(children.nth(0));
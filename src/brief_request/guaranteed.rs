@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use super::BriefRequest;
 use crate::data::address::Address;
 use crate::data::comment::Comment;
+use crate::data::deadlines::GuaranteedDeadlines;
 use crate::data::internal_status::InternalStatus;
 use crate::data::order_type::OrderType;
 use crate::data::status::Status;
@@ -33,6 +34,8 @@ pub struct GuaranteedBriefRequest {
     pub status:            Status,
     /// Will contain at least one element
     pub phones:            Vec<String>,
+    pub deadlines:         GuaranteedDeadlines,
+    pub scheduled_at:      Option<DateTime<FixedOffset>>,
 }
 
 impl BriefRequest {
@@ -57,6 +60,11 @@ impl BriefRequest {
             first_comment:     Some(self.first_comment?.ok()?),
             status:            self.status?.ok()?,
             phones:            self.phones,
+            deadlines:         self.deadlines.into_guaranteed()?,
+            scheduled_at:      match self.scheduled_at {
+                Some(scheduled_at) => Some(scheduled_at.ok()?),
+                None => None,
+            },
         })
     }
 }
@@ -17,14 +17,15 @@ pub mod guaranteed;
 use std::fmt::Debug;
 use std::str::FromStr;
 
-use chrono::{DateTime, FixedOffset, NaiveDateTime};
-use chrono_tz::Europe::Kyiv;
+use chrono::{DateTime, FixedOffset, NaiveDate};
 use itertools::Itertools;
 use scraper::{ElementRef, Html};
 use serde::{Deserialize, Serialize};
 
 use crate::data::address::Address;
 use crate::data::comment::{Comment, CommentError};
+use crate::data::deadlines::Deadlines;
+use crate::data::full_comment::FullComment;
 use crate::data::internal_status::{InternalStatus, InternalStatusError};
 use crate::data::order_type::{OrderType, OrderTypeError};
 use crate::data::status::{Status, StatusError};
@@ -62,6 +63,13 @@ pub struct BriefRequest {
     pub status:            Option<Result<Status, StatusError>>,
     /// List of client's contact phone numbers
     pub phones:            Vec<String>,
+    pub deadlines:         Deadlines,
+    /// Connection datetime, i.e. date and the start of time constrains the
+    /// order is scheduled for. `None` if either of them is missing or invalid
+    /// ([`TimeConstrains::starts_at`] errors only on DST transition). Named
+    /// `scheduled_at`, as it's the start of the scheduled visit rather than
+    /// the moment the client gets connected, which isn't known in advance
+    pub scheduled_at:      Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
 }
 
 impl PrevElementRef for BriefRequest {}
@@ -103,12 +111,13 @@ impl BriefRequest {
         let mut city = None;
         let mut address_string = None;
         let mut apartment = None;
+        let mut assigned_for = None;
 
         for element in elements {
             match_and_set!(
                 "заявка:",
                 self.internal_order_id,
-                (|| Some(u32::from_str(&cur_text).map_err(|err| err.kind().clone().into()))),
+                (|| Some(u32::from_str(&cur_text).map_err(|err| (*err.kind()).into()))),
                 self,
                 element,
                 text_getter,
@@ -119,7 +128,7 @@ impl BriefRequest {
             match_and_set!(
                 "наряд:",
                 self.order_id,
-                (|| Some(u32::from_str(&cur_text).map_err(|err| err.kind().clone().into()))),
+                (|| Some(u32::from_str(&cur_text).map_err(|err| (*err.kind()).into()))),
                 self,
                 element,
                 text_getter,
@@ -130,7 +139,7 @@ impl BriefRequest {
             match_and_set!(
                 "подтип:",
                 self.order_type,
-                (|| Some(OrderType::from_str(&cur_text))),
+                (|| Some(OrderType::from_subtype(None, &cur_text))),
                 self,
                 element,
                 text_getter,
@@ -141,7 +150,7 @@ impl BriefRequest {
             match_and_set!(
                 "дата создания:",
                 self.creation_date,
-                (|| Some(self.as_datetime(&cur_text))),
+                (|| Some(FullComment::as_datetime(&cur_text))),
                 self,
                 element,
                 text_getter,
@@ -291,6 +300,21 @@ impl BriefRequest {
                 cur_text
             );
 
+            if self.deadlines.collect(element, text_getter) {
+                continue;
+            }
+
+            match_and_set!(
+                "дата подключения:",
+                assigned_for,
+                (|| NaiveDate::parse_from_str(cur_text.trim(), "%d.%m.%Y").ok()),
+                self,
+                element,
+                text_getter,
+                is_it,
+                cur_text
+            );
+
             match_and_set!(
                 "начальный комментарий:",
                 self.first_comment,
@@ -314,6 +338,10 @@ impl BriefRequest {
             );
         }
 
+        if let (Some(assigned_for), Some(Ok(time_constrains))) = (assigned_for, &self.time_constrains) {
+            self.scheduled_at = Some(time_constrains.starts_at(assigned_for));
+        }
+
         if let (Some(city), Some(address_string), Some(apartment)) = (city, address_string, apartment) {
             let mut address_iter = address_string.split(',');
 
@@ -326,15 +354,4 @@ impl BriefRequest {
             ));
         }
     }
-
-    fn as_datetime(&self, input: &str) -> Result<DateTime<FixedOffset>, SerializableParseErrorKind> {
-        let naive = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S")?;
-        let datetime = naive
-            .and_local_timezone(Kyiv)
-            .earliest()
-            .expect("Never should have gotten a time that doesn't exist in the Kyiv time zone")
-            .fixed_offset();
-
-        Ok(datetime)
-    }
 }
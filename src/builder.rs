@@ -0,0 +1,217 @@
+//! [`Nsg`] builder
+//!
+//! ## Example usage
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use nsg::builder::{NsgBuilder, RetryPolicy};
+//! # use nsg::error::NsgError;
+//!
+//! # tokio_test::block_on(async {
+//! let nsg = NsgBuilder::new(
+//!     "https://net-stroy.itnet.lviv.ua".to_string(),
+//!     "client".to_string(),
+//!     "x.y".to_string(),
+//! )
+//! .connect_timeout(Duration::from_secs(5))
+//! .timeout(Duration::from_secs(30))
+//! .retry_policy(RetryPolicy::new(3, Duration::from_millis(500)))
+//! .build_with_creds("login".to_string(), "password_hash".to_string())
+//! .await?;
+//!
+//! println!("Session: {:?}", nsg.session());
+//!
+//! # Ok::<(), NsgError>(())
+//! # });
+//! ```
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use reqwest::{Certificate, Client, Proxy};
+
+use crate::error::{NsgError, NsgResult};
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::Nsg;
+
+/// Defines how many times and how often failed request will be retried. Only
+/// errors considered transient by [`NsgError::is_retryable`] are retried.
+/// Delay between attempts starts from [`RetryPolicy::initial_backoff`] and
+/// doubles after every attempt up to [`RetryPolicy::max_backoff`]
+///
+/// Default policy doesn't retry at all
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt. `0` disables retries
+    pub max_retries:     u32,
+    pub initial_backoff: Duration,
+    pub max_backoff:     Duration,
+}
+
+impl RetryPolicy {
+    /// Creates policy with exponential backoff capped at 30 seconds
+    pub fn new(max_retries: u32, initial_backoff: Duration) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            initial_backoff,
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+
+    /// Delay before retry number `attempt` (starting from `0`)
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use nsg::builder::RetryPolicy;
+    ///
+    /// let policy = RetryPolicy::new(5, Duration::from_secs(1));
+    ///
+    /// assert_eq!(policy.backoff(0), Duration::from_secs(1));
+    /// assert_eq!(policy.backoff(2), Duration::from_secs(4));
+    /// assert_eq!(policy.backoff(10), Duration::from_secs(30));
+    /// ```
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .checked_mul(2u32.saturating_pow(attempt))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+}
+
+/// Builder of [`Nsg`] allowing to configure underlying HTTP client and retry
+/// policy. [`Nsg::from_creds`] and [`Nsg::from_session`] are shortcuts for
+/// builder with default settings
+#[derive(Clone, Debug)]
+pub struct NsgBuilder {
+    root:              String,
+    http_client:       String,
+    http_client_ver:   String,
+    connect_timeout:   Option<Duration>,
+    timeout:           Option<Duration>,
+    proxy:             Option<String>,
+    root_certificates: Vec<Certificate>,
+    retry_policy:      RetryPolicy,
+    rate_limit:        Option<RateLimit>,
+    dry_run:           bool,
+}
+
+impl NsgBuilder {
+    /// Creates builder with default settings.
+    ///
+    /// **Note**: root must be without `/`, i.e. `https://net-stroy.itnet.lviv.ua` or `https://nsg.zp.ua`
+    pub fn new(root: String, http_client: String, http_client_ver: String) -> NsgBuilder {
+        NsgBuilder {
+            root,
+            http_client,
+            http_client_ver,
+            connect_timeout: None,
+            timeout: None,
+            proxy: None,
+            root_certificates: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            rate_limit: None,
+            dry_run: false,
+        }
+    }
+
+    /// Timeout for connection phase only
+    pub fn connect_timeout(mut self, timeout: Duration) -> NsgBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for the whole request: from connecting until the response body
+    /// has finished
+    pub fn timeout(mut self, timeout: Duration) -> NsgBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Proxy all requests through given proxy url, like `http://127.0.0.1:8080`
+    pub fn proxy(mut self, proxy: String) -> NsgBuilder {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Trust additional root certificate, e.g. parsed with
+    /// [`Certificate::from_pem`]
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> NsgBuilder {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> NsgBuilder {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limit rate and concurrency of requests to Portal. By default requests
+    /// aren't limited
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> NsgBuilder {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// In dry run mode write actions, like [`Nsg::add_comment`] or
+    /// [`Nsg::rollback_request`], log their payload instead of sending it.
+    /// Read actions are performed as usual. Disabled by default
+    pub fn dry_run(mut self, dry_run: bool) -> NsgBuilder {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Builds [`Nsg`] from credentials (login and password hash in md5) and
+    /// performs relogin to get a session. See [`Nsg::from_creds`]
+    pub async fn build_with_creds(self, login: String, password_hash: String) -> NsgResult<Nsg> {
+        let nsg = self.build(None, Some(login), Some(password_hash))?;
+
+        nsg.relogin()
+            .await
+            .map_err(|err| NsgError::FailedToRelogin(Box::new(err)))?;
+
+        Ok(nsg)
+    }
+
+    /// Builds [`Nsg`] that uses already existing session. See
+    /// [`Nsg::from_session`]
+    pub fn build_with_session(self, session: String) -> NsgResult<Nsg> { self.build(Some(session), None, None) }
+
+    fn build(self, session: Option<String>, login: Option<String>, password_hash: Option<String>) -> NsgResult<Nsg> {
+        let mut client_builder = Client::builder();
+
+        if let Some(timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(timeout);
+        }
+
+        if let Some(timeout) = self.timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
+
+        if let Some(proxy) = self.proxy {
+            client_builder = client_builder.proxy(Proxy::all(proxy)?);
+        }
+
+        for certificate in self.root_certificates {
+            client_builder = client_builder.add_root_certificate(certificate);
+        }
+
+        Ok(Nsg {
+            session: Arc::new(RwLock::new(session)),
+            relogin_lock: Arc::new(tokio::sync::Mutex::new(())),
+            client: client_builder.build()?,
+            root: self.root,
+            http_client: self.http_client,
+            http_client_ver: self.http_client_ver,
+            retry_policy: self.retry_policy,
+            rate_limiter: self
+                .rate_limit
+                .map(|rate_limit| Arc::new(RateLimiter::new(&rate_limit))),
+            dry_run: self.dry_run,
+
+            login,
+            password_hash,
+        })
+    }
+}
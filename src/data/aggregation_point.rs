@@ -0,0 +1,63 @@
+//! Aggregation point, i.e. the switch the building is connected to
+//!
+//! ## Example usage
+//!
+//! ```
+//! use std::str::FromStr;
+//!
+//! use nsg::data::aggregation_point::AggregationPoint;
+//! use nsg::data::technology::Technology;
+//!
+//! assert_eq!(
+//!     AggregationPoint::from_str("FTTB_Запоріжжя"),
+//!     Ok(AggregationPoint {
+//!         technology: Technology::FTTB,
+//!         name:       "Запоріжжя".to_string(),
+//!     })
+//! );
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::technology::{Technology, TechnologyError};
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum AggregationPointError {
+    /// Provided `&str` isn't in format `<technology>_<name>`
+    NoSeparator(String),
+    /// Technology prefix of the aggregation point is invalid
+    InvalidTechnology(TechnologyError),
+}
+
+/// "Точка агрегации" in format `<technology>_<name>`, like `FTTB_Запоріжжя`
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct AggregationPoint {
+    pub technology: Technology,
+    /// Usually the city
+    pub name:       String,
+}
+
+impl FromStr for AggregationPoint {
+    type Err = AggregationPointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (technology, name) = s
+            .trim()
+            .split_once('_')
+            .ok_or_else(|| AggregationPointError::NoSeparator(s.to_string()))?;
+
+        Ok(AggregationPoint {
+            technology: Technology::from_str(technology).map_err(AggregationPointError::InvalidTechnology)?,
+            name:       name.to_string(),
+        })
+    }
+}
+
+impl Display for AggregationPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.technology, self.name)
+    }
+}
@@ -0,0 +1,149 @@
+//! Cable tester output embedded into system comments
+//!
+//! Orders created from client's complaints start with a comment like
+//! `Індикатор "WAN" не горить;DOWN;Repair date-2024-05-26;Указать метр
+//! повреждения: Pair A length: 52meter(s) Pair B length: 56meter(s) Pair A
+//! state: Open Pair B state: Open`
+//!
+//! ## Example usage
+//!
+//! ```
+//! use nsg::data::cable_diagnostics::{CableDiagnostics, PairState};
+//!
+//! let diagnostics = CableDiagnostics::from(
+//!     "Індикатор \"WAN\" не горить;DOWN;Repair date-2024-05-26;Указать метр повреждения: Pair A length: \
+//!      52meter(s) Pair B length: 56meter(s) Pair A state: Open Pair B state: Open",
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(diagnostics.pairs[0].length, Some(52));
+//! assert_eq!(diagnostics.pairs[0].state, Some(PairState::Open));
+//! assert_eq!(diagnostics.fault_distance(), Some(52));
+//! assert_eq!(diagnostics.symptoms, vec!["Індикатор \"WAN\" не горить", "DOWN"]);
+//! ```
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use itertools::Itertools;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+/// "Указать метр повреждения", field the tester output is put into
+const FAULT_DISTANCE_LABEL: &str = "Указать метр повреждения";
+const REPAIR_DATE_LABEL: &str = "Repair date";
+
+/// State of the twisted pair reported by the cable tester
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum PairState {
+    Ok,
+    Open,
+    Short,
+    /// State not known to the library, kept as is
+    Other(String),
+}
+
+impl From<&str> for PairState {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "ok" | "normal" => PairState::Ok,
+            "open" => PairState::Open,
+            "short" => PairState::Short,
+            _ => PairState::Other(s.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct CablePair {
+    /// `A`, `B`, `C` or `D`
+    pub name:   char,
+    /// Length measured by the tester in meters. For broken pair it's the
+    /// distance to the fault
+    pub length: Option<u32>,
+    pub state:  Option<PairState>,
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct CableDiagnostics {
+    /// Sorted by name. Tester output is often repeated in the comment, the
+    /// last value wins
+    pub pairs:       Vec<CablePair>,
+    pub repair_date: Option<Result<NaiveDate, SerializableParseErrorKind>>,
+    /// Problems reported by the client, like `Індикатор "WAN" не горить`
+    pub symptoms:    Vec<String>,
+}
+
+impl CableDiagnostics {
+    /// Parse diagnostics from the text of the comment. Returns `None` if the
+    /// text has neither tester output nor repair date
+    pub fn from(text: &str) -> Option<CableDiagnostics> {
+        let length_regex = Regex::new(r"Pair (?<pair>[A-D]) length:\s*(?<length>\d+)\s*meter").unwrap();
+        let state_regex = Regex::new(r"Pair (?<pair>[A-D]) state:\s*(?<state>\w+)").unwrap();
+        let repair_date_regex = Regex::new(r"Repair date\s*-?\s*(?<date>\S+?)(;|\s|$)").unwrap();
+
+        let mut pairs = BTreeMap::new();
+
+        for captures in length_regex.captures_iter(text) {
+            CableDiagnostics::pair(&mut pairs, &captures["pair"]).length = u32::from_str(&captures["length"]).ok();
+        }
+
+        for captures in state_regex.captures_iter(text) {
+            CableDiagnostics::pair(&mut pairs, &captures["pair"]).state = Some(PairState::from(&captures["state"]));
+        }
+
+        let repair_date = repair_date_regex
+            .captures(text)
+            .map(|captures| NaiveDate::parse_from_str(&captures["date"], "%Y-%m-%d").map_err(|err| err.kind().into()));
+
+        if pairs.is_empty() && repair_date.is_none() {
+            return None;
+        }
+
+        // Only the first line is the template, the rest is written by people
+        let symptoms = text
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .split(';')
+            .map(str::trim)
+            .filter(|part| {
+                !part.is_empty() &&
+                    !part.starts_with(FAULT_DISTANCE_LABEL) &&
+                    !part.starts_with(REPAIR_DATE_LABEL) &&
+                    !part.contains("Pair ")
+            })
+            .map(str::to_string)
+            .unique()
+            .collect_vec();
+
+        Some(CableDiagnostics {
+            pairs: pairs.into_values().collect_vec(),
+            repair_date,
+            symptoms,
+        })
+    }
+
+    /// Distance to the closest fault in meters: the shortest length among
+    /// pairs which aren't [`PairState::Ok`]
+    pub fn fault_distance(&self) -> Option<u32> {
+        self.pairs
+            .iter()
+            .filter(|pair| pair.state != Some(PairState::Ok))
+            .filter_map(|pair| pair.length)
+            .min()
+    }
+
+    fn pair<'a>(pairs: &'a mut BTreeMap<char, CablePair>, name: &str) -> &'a mut CablePair {
+        let name = name.chars().next().expect("Regex always matches a letter");
+
+        pairs.entry(name).or_insert(CablePair {
+            name,
+            length: None,
+            state: None,
+        })
+    }
+}
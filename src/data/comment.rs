@@ -1,10 +1,11 @@
 //! Basic comment without additional information
 
-use chrono::{DateTime, FixedOffset, NaiveDateTime};
-use chrono_tz::Europe::Kyiv;
+use chrono::{DateTime, FixedOffset};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use super::cable_diagnostics::CableDiagnostics;
+use super::full_comment::FullComment;
 use crate::serializable_parse_error_kind::SerializableParseErrorKind;
 
 #[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
@@ -52,7 +53,7 @@ impl Comment {
         if let Some(caps) = regex.captures(&text) {
             let text = html_escape::decode_html_entities(regex.replace(&text, "").as_ref()).replace("<br/>", "\n");
             let user = Some(caps["user"].to_string());
-            let datetime = Some(Self::as_datetime(&caps["datetime"]));
+            let datetime = Some(FullComment::as_datetime(&caps["datetime"]));
 
             return Ok(Comment { text, user, datetime });
         };
@@ -64,14 +65,6 @@ impl Comment {
         })
     }
 
-    fn as_datetime(input: &str) -> Result<DateTime<FixedOffset>, SerializableParseErrorKind> {
-        let naive = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S")?;
-        let datetime = naive
-            .and_local_timezone(Kyiv)
-            .earliest()
-            .expect("Never should have gotten a time that doesn't exist in the Kyiv time zone")
-            .fixed_offset();
-
-        Ok(datetime)
-    }
+    /// See [`FullComment::diagnostics`](super::full_comment::FullComment::diagnostics)
+    pub fn diagnostics(&self) -> Option<CableDiagnostics> { CableDiagnostics::from(&self.text) }
 }
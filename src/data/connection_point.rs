@@ -0,0 +1,61 @@
+//! Connection point (device or service) of the order
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use scraper::ElementRef;
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum ConnectionPointError {
+    /// Row doesn't have name and three counts
+    NotEnoughCells(usize),
+    /// One of the counts can't be parsed as `u32`
+    InvalidCount(SerializableIntErrorKind),
+}
+
+/// Row of "Точки подключения" table: how many of the devices client buys,
+/// rents or takes for safekeeping
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct ConnectionPoint {
+    /// "Название", like `Роутер PRO` or `Gigabit`
+    pub name:        String,
+    /// "Выкуп"
+    pub buyout:      u32,
+    /// "Аренда"
+    pub rent:        u32,
+    /// "Отв.хранение"
+    pub safekeeping: u32,
+    /// "Пакеты"
+    pub packages:    Option<String>,
+}
+
+impl ConnectionPoint {
+    /// Parse connection point from `<tr>` of the table
+    pub fn from(row: ElementRef) -> Result<ConnectionPoint, ConnectionPointError> {
+        log::debug!(target: "nsg", "Creating connection point from {}", row.html());
+        let cells = row
+            .child_elements()
+            .map(|cell| cell.text().collect::<String>().trim().to_string())
+            .collect_vec();
+
+        let [name, buyout, rent, safekeeping, rest @ ..] = cells.as_slice() else {
+            return Err(ConnectionPointError::NotEnoughCells(cells.len()));
+        };
+        let count =
+            |text: &str| u32::from_str(text).map_err(|err| ConnectionPointError::InvalidCount((*err.kind()).into()));
+
+        Ok(ConnectionPoint {
+            name:        name.clone(),
+            buyout:      count(buyout)?,
+            rent:        count(rent)?,
+            safekeeping: count(safekeeping)?,
+            packages:    rest.first().filter(|packages| !packages.is_empty()).cloned(),
+        })
+    }
+
+    /// Total number of devices of any kind
+    pub fn total(&self) -> u32 { self.buyout + self.rent + self.safekeeping }
+}
@@ -0,0 +1,114 @@
+//! Status of the order at Kyivstar's side ("Статус у заказчика")
+//!
+//! View request shows it in its own row. When Kyivstar changes status of the
+//! order, Portal also adds a comment like
+//! `Статус у заказчика:Отклонена. Переведено в Не активировано` and, if
+//! needed, changes the internal status of the order accordingly
+//!
+//! ## Example usage
+//!
+//! ```
+//! use chrono::DateTime;
+//! use nsg::data::customer_status::CustomerStatus;
+//! use nsg::data::internal_status::InternalStatus;
+//! use nsg::data::status::Status;
+//!
+//! assert_eq!(
+//!     CustomerStatus::from(
+//!         "Статус у заказчика:Отклонена. Переведено в Не активировано (Іван Іванович Іванов,2024-05-16 \
+//!          16:42:20)"
+//!     ),
+//!     Ok(CustomerStatus {
+//!         status:          Status::Declined,
+//!         internal_status: Some(InternalStatus::NotActivated),
+//!         user:            Some("Іван Іванович Іванов".to_string()),
+//!         datetime:        Some(DateTime::parse_from_rfc3339("2024-05-16 16:42:20+03:00").unwrap()),
+//!     })
+//! );
+//! ```
+
+use std::str::FromStr;
+
+use chrono::{DateTime, FixedOffset};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::comment::{Comment, CommentError};
+use super::full_comment::FullComment;
+use super::internal_status::{InternalStatus, InternalStatusError};
+use super::status::{Status, StatusError};
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+/// Prefix of comments Portal adds on status change made by Kyivstar
+pub const CUSTOMER_STATUS_PREFIX: &str = "Статус у заказчика:";
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum CustomerStatusError {
+    /// Provided `&str` didn't match the regex and thus can't be represented as
+    /// [`CustomerStatus`]
+    NoMatch,
+    InvalidStatus(StatusError),
+    InvalidInternalStatus(InternalStatusError),
+    DateTimeFailed(String, SerializableParseErrorKind),
+}
+
+/// Kyivstar's [`Status`], the [`InternalStatus`] order was moved to because of
+/// it, and who and when did it
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct CustomerStatus {
+    pub status:          Status,
+    /// `None` if internal status hasn't changed
+    pub internal_status: Option<InternalStatus>,
+    pub user:            Option<String>,
+    pub datetime:        Option<DateTime<FixedOffset>>,
+}
+
+impl CustomerStatus {
+    /// Parse customer status from the value of "Статус у заказчика" row or
+    /// the text of the comment. Both prefix and `(user,datetime)` suffix are
+    /// optional
+    pub fn from(text: &str) -> Result<CustomerStatus, CustomerStatusError> {
+        let comment = Comment::from(text)
+            .map_err(|CommentError::DateTimeFailed(input, kind)| CustomerStatusError::DateTimeFailed(input, kind))?;
+
+        let regex = Regex::new(r"^\s*(?<status>[^.]*?)\s*\.?\s*(Переведено в\s*(?<internal_status>.*?))?\s*$").unwrap();
+        let text = comment.text.trim();
+        let captures = regex
+            .captures(text.strip_prefix(CUSTOMER_STATUS_PREFIX).unwrap_or(text))
+            .ok_or(CustomerStatusError::NoMatch)?;
+
+        Ok(CustomerStatus {
+            status:          Status::from_str(&captures["status"]).map_err(CustomerStatusError::InvalidStatus)?,
+            internal_status: match captures.name("internal_status") {
+                Some(internal_status) => Some(
+                    InternalStatus::from_str(internal_status.as_str())
+                        .map_err(CustomerStatusError::InvalidInternalStatus)?,
+                ),
+                None => None,
+            },
+            user:            comment.user,
+            datetime:        match comment.datetime {
+                Some(datetime) => {
+                    Some(datetime.map_err(|kind| CustomerStatusError::DateTimeFailed(text.to_string(), kind))?)
+                },
+                None => None,
+            },
+        })
+    }
+
+    /// Parse customer status from the comment of view request. Returns `None`
+    /// if it isn't a status change made by Kyivstar
+    pub fn from_full_comment(full_comment: &FullComment) -> Option<Result<CustomerStatus, CustomerStatusError>> {
+        let text = full_comment.text.as_ref()?;
+
+        if !text.trim_start().starts_with(CUSTOMER_STATUS_PREFIX) {
+            return None;
+        }
+
+        Some(CustomerStatus::from(text).map(|customer_status| CustomerStatus {
+            user: customer_status.user.or_else(|| Some(full_comment.user.clone())),
+            datetime: customer_status.datetime.or(Some(full_comment.datetime)),
+            ..customer_status
+        }))
+    }
+}
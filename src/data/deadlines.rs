@@ -0,0 +1,115 @@
+//! Urgency and deadlines of the order, shown the same way by brief and view
+//! requests
+//!
+//! ## Example usage
+//!
+//! ```
+//! use nsg::view_request::ViewRequest;
+//!
+//! let html = include_str!("../tests/assets/view_request/valid/1.html");
+//! let deadlines = ViewRequest::from(&html).deadlines;
+//!
+//! println!("Deadlines: {:#?}", deadlines);
+//! ```
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use scraper::ElementRef;
+use serde::{Deserialize, Serialize};
+
+use super::full_comment::FullComment;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+use crate::traits::is_it::IsIt;
+use crate::traits::prev_element_ref::PrevElementRef;
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct Deadlines {
+    /// "Срочная"
+    pub urgent:             bool,
+    /// "Срок выполнения"
+    pub deadline:           Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// "Плановая дата включения"
+    pub planned_activation: Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// "Желаемое время подключения", time client asked for
+    pub desired_time:       Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// "Дата перезвона"
+    pub callback_date:      Option<Result<NaiveDate, SerializableParseErrorKind>>,
+}
+
+/// Hence [`Deadlines`] will not fail hard, it's not necessary valid. You can
+/// guarantee validness with [`Deadlines::into_guaranteed`]. Missing deadlines
+/// stay `None`
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct GuaranteedDeadlines {
+    pub urgent:             bool,
+    pub deadline:           Option<DateTime<FixedOffset>>,
+    pub planned_activation: Option<DateTime<FixedOffset>>,
+    pub desired_time:       Option<DateTime<FixedOffset>>,
+    pub callback_date:      Option<NaiveDate>,
+}
+
+impl PrevElementRef for Deadlines {}
+
+impl Deadlines {
+    pub fn into_guaranteed(self) -> Option<GuaranteedDeadlines> {
+        Some(GuaranteedDeadlines {
+            urgent:             self.urgent,
+            deadline:           match self.deadline {
+                Some(deadline) => Some(deadline.ok()?),
+                None => None,
+            },
+            planned_activation: match self.planned_activation {
+                Some(planned_activation) => Some(planned_activation.ok()?),
+                None => None,
+            },
+            desired_time:       match self.desired_time {
+                Some(desired_time) => Some(desired_time.ok()?),
+                None => None,
+            },
+            callback_date:      match self.callback_date {
+                Some(callback_date) => Some(callback_date.ok()?),
+                None => None,
+            },
+        })
+    }
+
+    /// Sets the field if `element` is the value of one of the deadlines rows.
+    /// Returns whether it was
+    pub(crate) fn collect(
+        &mut self,
+        element: ElementRef<'_>,
+        text_getter: impl Fn(ElementRef<'_>) -> String + Copy,
+    ) -> bool {
+        if let Some(text) = self.is_it("срочная:", element, text_getter) {
+            log::info!(target: "nsg", "[{:?}] Found deadlines.urgent, inner text: {:?}", element.id(), &text);
+            self.urgent = text.trim() == "Да";
+            return true;
+        }
+
+        if let Some(text) = self.is_it("срок выполнения:", element, text_getter) {
+            log::info!(target: "nsg", "[{:?}] Found deadlines.deadline, inner text: {:?}", element.id(), &text);
+            self.deadline = Some(FullComment::as_datetime(text.trim()));
+            return true;
+        }
+
+        if let Some(text) = self.is_it("плановая дата включения:", element, text_getter) {
+            log::info!(target: "nsg", "[{:?}] Found deadlines.planned_activation, inner text: {:?}", element.id(), &text);
+            self.planned_activation = Some(FullComment::as_datetime(text.trim()));
+            return true;
+        }
+
+        if let Some(text) = self.is_it("желаемое время подключения:", element, text_getter) {
+            log::info!(target: "nsg", "[{:?}] Found deadlines.desired_time, inner text: {:?}", element.id(), &text);
+            self.desired_time = Some(FullComment::as_datetime(text.trim()));
+            return true;
+        }
+
+        if let Some(text) = self.is_it("дата перезвона:", element, text_getter) {
+            log::info!(target: "nsg", "[{:?}] Found deadlines.callback_date, inner text: {:?}", element.id(), &text);
+            self.callback_date =
+                Some(NaiveDate::parse_from_str(text.trim(), "%d.%m.%Y").map_err(|err| err.kind().into()));
+            return true;
+        }
+
+        false
+    }
+}
@@ -0,0 +1,55 @@
+//! Equipment and materials used for the order
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use scraper::ElementRef;
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum EquipmentError {
+    /// Row doesn't have all five cells
+    NotEnoughCells(usize),
+    /// Quantity can't be parsed as `u32`
+    InvalidQuantity(SerializableIntErrorKind),
+}
+
+/// Row of "Оборудование" table, like `Кабель UTP | 40 | 4х2х0,51 БМ |
+/// Выдать(абонент) | -Абонент-`
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct Equipment {
+    pub item:     String,
+    /// Pieces or meters, depending on the item
+    pub quantity: u32,
+    /// Specification, like `4х2х0,51 БМ`
+    pub spec:     Option<String>,
+    /// What was done with the item, like `Выдать(абонент)`
+    pub action:   String,
+    /// Who the item belongs to, like `-Абонент-`
+    pub owner:    String,
+}
+
+impl Equipment {
+    /// Parse equipment from `<tr>` of the table
+    pub fn from(row: ElementRef) -> Result<Equipment, EquipmentError> {
+        log::debug!(target: "nsg", "Creating equipment from {}", row.html());
+        let cells = row
+            .child_elements()
+            .map(|cell| cell.text().collect::<String>().trim().to_string())
+            .collect_vec();
+
+        let [item, quantity, spec, action, owner] = cells.as_slice() else {
+            return Err(EquipmentError::NotEnoughCells(cells.len()));
+        };
+
+        Ok(Equipment {
+            item:     item.clone(),
+            quantity: u32::from_str(quantity).map_err(|err| EquipmentError::InvalidQuantity((*err.kind()).into()))?,
+            spec:     (!spec.is_empty()).then(|| spec.clone()),
+            action:   action.clone(),
+            owner:    owner.clone(),
+        })
+    }
+}
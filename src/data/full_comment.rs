@@ -8,6 +8,7 @@ use itertools::Itertools;
 use scraper::ElementRef;
 use serde::{Deserialize, Serialize};
 
+use super::cable_diagnostics::CableDiagnostics;
 use super::internal_status::{InternalStatus, InternalStatusError};
 use crate::serializable_parse_error_kind::SerializableParseErrorKind;
 
@@ -97,14 +98,25 @@ impl FullComment {
         })
     }
 
-    fn as_datetime(input: &str) -> Result<DateTime<FixedOffset>, SerializableParseErrorKind> {
+    /// Cable tester output and client's symptoms, usually found in the first
+    /// comment. See [`CableDiagnostics::from`]
+    pub fn diagnostics(&self) -> Option<CableDiagnostics> { CableDiagnostics::from(self.text.as_ref()?) }
+
+    /// Portal's datetimes are in Kyiv time zone and look like
+    /// `2024-05-16 14:17:49`
+    pub(crate) fn as_datetime(input: &str) -> Result<DateTime<FixedOffset>, SerializableParseErrorKind> {
         let naive = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S")?;
-        let datetime = naive
+
+        FullComment::in_kyiv(naive)
+    }
+
+    /// Time skipped by transition to daylight saving time doesn't exist in
+    /// Kyiv time zone and is [`SerializableParseErrorKind::Impossible`]
+    pub(crate) fn in_kyiv(naive: NaiveDateTime) -> Result<DateTime<FixedOffset>, SerializableParseErrorKind> {
+        naive
             .and_local_timezone(Kyiv)
             .earliest()
-            .expect("Never should have gotten a time that doesn't exist in the Kyiv time zone")
-            .fixed_offset();
-
-        Ok(datetime)
+            .map(|datetime| datetime.fixed_offset())
+            .ok_or(SerializableParseErrorKind::Impossible)
     }
 }
@@ -1,5 +1,6 @@
 //! Portal's order status
 
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
@@ -43,3 +44,23 @@ impl FromStr for InternalStatus {
         }
     }
 }
+
+/// Formats status the way Portal shows it, i.e. inverse of
+/// [`InternalStatus::from_str`]
+impl Display for InternalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            InternalStatus::Assigned => "Назначено",
+            InternalStatus::Returned => "Возврат (от монтажника)",
+            InternalStatus::Completed => "Выполнено",
+            InternalStatus::Rejected => "Отказ",
+            InternalStatus::NotActivated => "Не активировано",
+            InternalStatus::New => "Новая",
+            InternalStatus::Agreed => "Договорено",
+            InternalStatus::NotCompleted => "Не выполнено",
+            InternalStatus::Delayed => "Отложено",
+        };
+
+        write!(f, "{status}")
+    }
+}
@@ -4,10 +4,18 @@
 //! are smallest possible representations of data they will
 
 pub mod address;
+pub mod aggregation_point;
+pub mod cable_diagnostics;
 pub mod comment;
+pub mod connection_point;
+pub mod customer_status;
+pub mod deadlines;
+pub mod equipment;
 pub mod full_comment;
 pub mod internal_status;
 pub mod mdu;
 pub mod order_type;
+pub mod service;
 pub mod status;
+pub mod technology;
 pub mod time_constrains;
@@ -1,82 +1,198 @@
 //! Type of work on order
 //!
+//! Portal describes type of work with two levels: [`OrderCategory`] ("Тип",
+//! like "Сервис") and [`OrderSubtype`] ("Подтип", like "Тюнер ДЕМОНТАЖ").
+//! Work schedule joins them with `-`, while brief request shows only the
+//! subtype. [`OrderType`] keeps both of them
+//!
 //! # Terminology
 //!
 //! - **Active** - order for subscription (connection) to Kyivstar's network
 //!   created from inside "Нет-Строй"
 //! - **Passive** - as opposed to Active, it's order created by whoever outside
 //!   "Нет-Строй"
-//! - [`OrderType::SomeBitchComplained`] - is also used to give additional
+//! - [`OrderSubtype::ConnectionComplaints`] - is also used to give additional
 //!   information for installer and/or logistician but who fucking cares, shut
 //!   up your complaining mouth 😇
+//!
+//! ## Example usage
+//!
+//! ```
+//! use std::str::FromStr;
+//!
+//! use nsg::data::order_type::{OrderCategory, OrderSubtype, OrderType};
+//!
+//! let order_type = OrderType::from_str("Актив-Новое подключение").unwrap();
+//!
+//! assert_eq!(order_type, OrderType {
+//!     category: Some(OrderCategory::Active),
+//!     subtype:  OrderSubtype::NewConnection,
+//! });
+//! assert_eq!(order_type.to_string(), "Актив-Новое подключение");
+//! ```
 
+use std::fmt::Display;
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
 pub enum OrderTypeError {
-    /// Provided `&str` didn't match any order type and thus can't be
-    /// represented as [`OrderType`]
+    /// Provided `&str` is empty and thus can't be represented as [`OrderType`]
     InvalidOrderType(String),
 }
 
-/// Order type is a type of work on order that needs to be done
+/// "Тип" of the order
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum OrderCategory {
+    /// "Сервис"
+    Service,
+    /// "Актив"
+    Active,
+    /// "Пассив"
+    Passive,
+    /// Category not known to the library, kept as is
+    Unrecognized(String),
+}
+
+impl From<&str> for OrderCategory {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "Сервис" => OrderCategory::Service,
+            "Актив" => OrderCategory::Active,
+            "Пассив" => OrderCategory::Passive,
+            s => OrderCategory::Unrecognized(s.to_string()),
+        }
+    }
+}
+
+impl Display for OrderCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let category = match self {
+            OrderCategory::Service => "Сервис",
+            OrderCategory::Active => "Актив",
+            OrderCategory::Passive => "Пассив",
+            OrderCategory::Unrecognized(category) => category,
+        };
+
+        write!(f, "{category}")
+    }
+}
+
+/// "Подтип" of the order
 #[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
-pub enum OrderType {
+pub enum OrderSubtype {
+    /// "Тюнер ДЕМОНТАЖ"
     TunerRemoval,
+    /// "Тюнер УСТАНОВКА"
     TunerInstallation,
+    /// "Тюнер ЗАМЕНА"
     TunerReplacement,
-    NetGigabit,
-    NetRecoveryActive,
-    NetRecoveryPassive,
-    NetNewActive,
-    NetNewPassive,
-    NetBroken,
-    OthersMaster,
-    NetRelocationActive,
-    NetRelocationPassive,
-    SomeBitchComplained,
-    NetRecoveryUnknown,
-    NetNewUnknown,
-    NetRelocationUnknown,
+    /// "Гігабіт"
+    Gigabit,
+    /// "Восстановление"
+    Recovery,
+    /// "Новое подключение"
+    NewConnection,
+    /// "Жалобы на обслуживание"
+    ServiceComplaints,
+    /// "Мастер", may be followed by details like `Корпоративный`
+    Master(Option<String>),
+    /// "Переезд"
+    Relocation,
+    /// "Жалобы по включениям"
+    ConnectionComplaints,
+    /// Subtype not known to the library, kept as is
+    Unrecognized(String),
+}
+
+impl From<&str> for OrderSubtype {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "Тюнер ДЕМОНТАЖ" => OrderSubtype::TunerRemoval,
+            "Тюнер УСТАНОВКА" => OrderSubtype::TunerInstallation,
+            "Тюнер ЗАМЕНА" => OrderSubtype::TunerReplacement,
+            "Гігабіт" => OrderSubtype::Gigabit,
+            "Восстановление" => OrderSubtype::Recovery,
+            "Новое подключение" => OrderSubtype::NewConnection,
+            "Жалобы на обслуживание" => OrderSubtype::ServiceComplaints,
+            s if s.starts_with("Мастер") => {
+                let details = s.trim_start_matches("Мастер").trim();
+
+                OrderSubtype::Master((!details.is_empty()).then(|| details.to_string()))
+            },
+            "Переезд" => OrderSubtype::Relocation,
+            "Жалобы по включениям" => OrderSubtype::ConnectionComplaints,
+            s => OrderSubtype::Unrecognized(s.to_string()),
+        }
+    }
+}
+
+impl Display for OrderSubtype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let subtype = match self {
+            OrderSubtype::Master(Some(details)) => return write!(f, "Мастер {details}"),
+            OrderSubtype::TunerRemoval => "Тюнер ДЕМОНТАЖ",
+            OrderSubtype::TunerInstallation => "Тюнер УСТАНОВКА",
+            OrderSubtype::TunerReplacement => "Тюнер ЗАМЕНА",
+            OrderSubtype::Gigabit => "Гігабіт",
+            OrderSubtype::Recovery => "Восстановление",
+            OrderSubtype::NewConnection => "Новое подключение",
+            OrderSubtype::ServiceComplaints => "Жалобы на обслуживание",
+            OrderSubtype::Master(None) => "Мастер",
+            OrderSubtype::Relocation => "Переезд",
+            OrderSubtype::ConnectionComplaints => "Жалобы по включениям",
+            OrderSubtype::Unrecognized(subtype) => subtype,
+        };
+
+        write!(f, "{subtype}")
+    }
+}
+
+/// Order type is a type of work on order that needs to be done. Category and
+/// subtype are kept as Portal shows them, so no combination of them is lost
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct OrderType {
+    /// `None` if Portal doesn't show it, i.e. in brief request
+    pub category: Option<OrderCategory>,
+    pub subtype:  OrderSubtype,
+}
+
+impl OrderType {
+    /// Order type from category (if known) and subtype as Portal shows it.
+    /// Empty subtype is [`OrderTypeError::InvalidOrderType`]
+    pub fn from_subtype(category: Option<OrderCategory>, subtype: &str) -> Result<OrderType, OrderTypeError> {
+        let subtype = subtype.trim();
+
+        if subtype.is_empty() {
+            return Err(OrderTypeError::InvalidOrderType(subtype.to_string()));
+        }
+
+        Ok(OrderType {
+            category,
+            subtype: OrderSubtype::from(subtype),
+        })
+    }
 }
 
 impl FromStr for OrderType {
     type Err = OrderTypeError;
 
-    // TODO: There's no "Work schedule format" or "Brief request format"
-    // TODO: It's just type and subtype: Order of type "Сервис" and subtype
-    // TODO: "Тюнер ДЕМОНТАЖ"
+    /// Accepts `<category>-<subtype>` (work schedule) and `<subtype>` without
+    /// category
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            // --- Work schedule format ---
-            "Сервис-Тюнер ДЕМОНТАЖ" => Ok(OrderType::TunerRemoval),
-            "Сервис-Тюнер ЗАМЕНА" => Ok(OrderType::TunerReplacement),
-            "Сервис-Тюнер УСТАНОВКА" => Ok(OrderType::TunerInstallation),
-            "Сервис-Гігабіт" => Ok(OrderType::NetGigabit),
-            "Актив-Восстановление" => Ok(OrderType::NetRecoveryActive),
-            "Пассив-Восстановление" => Ok(OrderType::NetRecoveryPassive),
-            "Актив-Новое подключение" => Ok(OrderType::NetNewActive),
-            "Пассив-Новое подключение" => Ok(OrderType::NetNewPassive),
-            "Сервис-Жалобы на обслуживание" => Ok(OrderType::NetBroken),
-            s if s.contains("Сервис-Мастер") => Ok(OrderType::OthersMaster),
-            "Актив-Переезд" => Ok(OrderType::NetRelocationActive),
-            "Пассив-Переезд" => Ok(OrderType::NetRelocationPassive),
-            "Сервис-Жалобы по включениям" => Ok(OrderType::SomeBitchComplained),
-            // --- Brief request format ---
-            "Тюнер ДЕМОНТАЖ" => Ok(OrderType::TunerRemoval),
-            "Тюнер ЗАМЕНА" => Ok(OrderType::TunerReplacement),
-            "Тюнер УСТАНОВКА" => Ok(OrderType::TunerInstallation),
-            "Гігабіт" => Ok(OrderType::NetGigabit),
-            "Восстановление" => Ok(OrderType::NetRecoveryUnknown),
-            "Новое подключение" => Ok(OrderType::NetNewUnknown),
-            "Жалобы на обслуживание" => Ok(OrderType::NetBroken),
-            "Мастер" => Ok(OrderType::OthersMaster),
-            "Переезд" => Ok(OrderType::NetRelocationUnknown),
-            "Жалобы по включениям" => Ok(OrderType::SomeBitchComplained),
-
-            _ => Err(OrderTypeError::InvalidOrderType(s.to_string())),
+        match s.trim().split_once('-') {
+            Some((category, subtype)) => OrderType::from_subtype(Some(OrderCategory::from(category)), subtype),
+            None => OrderType::from_subtype(None, s),
+        }
+    }
+}
+
+impl Display for OrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.category {
+            Some(category) => write!(f, "{category}-{}", self.subtype),
+            None => write!(f, "{}", self.subtype),
         }
     }
 }
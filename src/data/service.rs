@@ -0,0 +1,54 @@
+//! Service of the order from view request's "Услуги" table
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use scraper::ElementRef;
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum ServiceError {
+    /// Row doesn't have `rowid` attribute
+    NoRowId,
+    /// `rowid` can't be parsed as `u32`
+    InvalidRowId(SerializableIntErrorKind),
+    /// Row doesn't have name of the service
+    NoName,
+}
+
+/// Row of "Услуги" table, like `Подключение к сети FTTB | Подтип Гігабіт`
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct Service {
+    /// `rowid` of the row, used to fetch details with
+    /// [`Nsg::service_hint`](crate::Nsg::service_hint)
+    pub id:     u32,
+    /// "Услуга", like `Подключение к сети FTTB`
+    pub name:   String,
+    /// "Источник", what service was added by, like `Подтип Гігабіт`
+    pub source: Option<String>,
+}
+
+impl Service {
+    /// Parse service from `<tr class="servicesrow">` of the table
+    pub fn from(row: ElementRef) -> Result<Service, ServiceError> {
+        log::debug!(target: "nsg", "Creating service from {}", row.html());
+        let id = row.attr("rowid").ok_or(ServiceError::NoRowId)?;
+        let cells = row
+            .child_elements()
+            .map(|cell| cell.text().collect::<String>().trim().to_string())
+            .collect_vec();
+
+        let name = cells
+            .first()
+            .filter(|name| !name.is_empty())
+            .ok_or(ServiceError::NoName)?;
+
+        Ok(Service {
+            id:     u32::from_str(id).map_err(|err| ServiceError::InvalidRowId((*err.kind()).into()))?,
+            name:   name.clone(),
+            source: cells.get(1).filter(|source| !source.is_empty()).cloned(),
+        })
+    }
+}
@@ -0,0 +1,61 @@
+//! Network technology the client is connected with
+//!
+//! ## Example usage
+//!
+//! ```
+//! use std::str::FromStr;
+//!
+//! use nsg::data::technology::Technology;
+//!
+//! assert_eq!(Technology::from_str("FTTB"), Ok(Technology::FTTB));
+//! assert_eq!(Technology::from_str("gpon"), Ok(Technology::GPON));
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum TechnologyError {
+    /// Provided `&str` didn't match any technology and thus can't be
+    /// represented as [`Technology`]
+    InvalidTechnology(String),
+}
+
+/// "Технология" of the order
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum Technology {
+    /// Fiber to the building, the last mile is a twisted pair
+    FTTB,
+    /// Fiber to the home
+    FTTH,
+    GPON,
+    XDSL,
+}
+
+impl FromStr for Technology {
+    type Err = TechnologyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "FTTB" => Ok(Technology::FTTB),
+            "FTTH" => Ok(Technology::FTTH),
+            "GPON" => Ok(Technology::GPON),
+            "XDSL" => Ok(Technology::XDSL),
+            _ => Err(TechnologyError::InvalidTechnology(s.to_string())),
+        }
+    }
+}
+
+impl Display for Technology {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Technology::FTTB => write!(f, "FTTB"),
+            Technology::FTTH => write!(f, "FTTH"),
+            Technology::GPON => write!(f, "GPON"),
+            Technology::XDSL => write!(f, "xDSL"),
+        }
+    }
+}
@@ -22,11 +22,14 @@
 //! );
 //! ```
 
-use chrono::NaiveTime;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime};
 use itertools::Itertools;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use super::full_comment::FullComment;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
 #[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
 pub enum TimeConstrainsError {
     // TODO: improve errors
@@ -53,6 +56,13 @@ pub struct TimeConstrains {
 }
 
 impl TimeConstrains {
+    /// Start of the period on the given date in Kyiv time.
+    /// [`SerializableParseErrorKind::Impossible`] if it's skipped by
+    /// transition to daylight saving time
+    pub fn starts_at(&self, date: NaiveDate) -> Result<DateTime<FixedOffset>, SerializableParseErrorKind> {
+        FullComment::in_kyiv(date.and_time(self.from))
+    }
+
     /// Will create [`TimeConstrains`] from string in
     /// `с (\d\d):(\d\d) до (\d\d):(\d\d)` format
     pub fn from(text: &str) -> Result<TimeConstrains, TimeConstrainsError> {
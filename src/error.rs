@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::incidents::incident_draft::IncidentDraftError;
+
 pub type NsgResult<T> = Result<T, NsgError>;
 
 #[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
@@ -10,25 +12,66 @@ pub enum NsgError {
     NoSessionCookie,
     /// Failed to relogin due to another [`NsgError`]
     FailedToRelogin(Box<NsgError>),
+    /// Portal responded with login page and session can't be renewed, either
+    /// because instance was created with
+    /// [`Nsg::from_session`](crate::Nsg::from_session) or because relogin
+    /// didn't help
+    SessionExpired,
+    /// [`Order`](crate::work_schedule::order::Order) has no valid internal
+    /// order id, so its data pillars can't be fetched
+    NoInternalOrderId,
+    /// View request has no valid address id, so staircases of the building
+    /// can't be fetched
+    NoAddressId,
+    /// Comment was sent, but it didn't appear in the follow-up view request
+    CommentNotFound,
+    /// Portal refused to perform the action, contains its message
+    ActionFailed(String),
+    /// Incident draft didn't pass validation against catalogue of subtypes
+    InvalidIncidentDraft(IncidentDraftError),
+    /// View request page didn't contain `sessioncode` required to fetch the
+    /// order itself
+    NoSessionCode,
+    /// Portal responded with non-2xx HTTP status code
+    UnsuccessfulStatus(u16),
+    /// Portal responded with body that isn't valid UTF-8
+    InvalidUtf8Body,
+    /// Response can't be deserialized into requested type, contains serde's
+    /// error message
+    DeserializeError(String),
     ReqwestTimeout,
     ReqwestRequestError,
     ReqwestConnectError,
     ReqwestBodyError,
     ReqwestDecodeError,
+    ReqwestBuilderError,
     ReqwestUnknownError,
     UrlEncodeError,
 }
 
+impl NsgError {
+    /// Whether error is transient (timeout, connection or body failure) and
+    /// request may succeed if retried. Used by
+    /// [`RetryPolicy`](crate::builder::RetryPolicy)
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            NsgError::ReqwestTimeout | NsgError::ReqwestConnectError | NsgError::ReqwestBodyError
+        )
+    }
+}
+
 impl From<reqwest::Error> for NsgError {
     fn from(err: reqwest::Error) -> Self {
-        if err.is_timeout() {
+        if err.is_builder() {
+            return NsgError::ReqwestBuilderError;
+        } else if err.is_timeout() {
             return NsgError::ReqwestTimeout;
-        } else if err.is_request() {
-            return NsgError::ReqwestRequestError;
         } else if err.is_connect() {
+            // reqwest reports connect failures as request errors too
             return NsgError::ReqwestConnectError;
-        } else if err.is_decode() {
-            return NsgError::ReqwestDecodeError;
+        } else if err.is_request() {
+            return NsgError::ReqwestRequestError;
         } else if err.is_body() {
             return NsgError::ReqwestBodyError;
         } else if err.is_decode() {
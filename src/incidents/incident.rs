@@ -0,0 +1,58 @@
+//! Order incident
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+/// Parsed incident of the order. For detailed information about field refer
+/// to it's documentation. Note that all fields will not fail hard allowing to
+/// work with partially valid incidents
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct Incident {
+    /// Id of incident, the one used in `?action=editIncident&id=`
+    pub id:            Option<Result<u32, SerializableIntErrorKind>>,
+    pub incident_type: Option<String>,
+    pub subtype:       Option<String>,
+    pub status:        Option<String>,
+    pub description:   Option<String>,
+    pub created:       Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    pub updated:       Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// Full name of user who created incident
+    pub author:        Option<String>,
+}
+
+/// Hence [`Incident`] will not fail hard, it's not necessary valid. You can
+/// guarantee validness of incident with [`Incident::into_guaranteed`]. For
+/// detailed information about field refer to it's documentation or
+/// [`Incident`]'s documentation
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct GuaranteedIncident {
+    pub id:            u32,
+    pub incident_type: String,
+    pub subtype:       Option<String>,
+    pub status:        String,
+    pub description:   Option<String>,
+    pub created:       DateTime<FixedOffset>,
+    pub updated:       Option<DateTime<FixedOffset>>,
+    pub author:        String,
+}
+
+impl Incident {
+    pub fn into_guaranteed(self) -> Option<GuaranteedIncident> {
+        Some(GuaranteedIncident {
+            id:            self.id?.ok()?,
+            incident_type: self.incident_type?,
+            subtype:       self.subtype,
+            status:        self.status?,
+            description:   self.description,
+            created:       self.created?.ok()?,
+            updated:       match self.updated {
+                Some(updated) => Some(updated.ok()?),
+                None => None,
+            },
+            author:        self.author?,
+        })
+    }
+}
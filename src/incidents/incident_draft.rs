@@ -0,0 +1,57 @@
+//! Incident to be created with
+//! [`Nsg::create_incident`](crate::Nsg::create_incident)
+
+use serde::{Deserialize, Serialize};
+
+use super::incident_subtypes::IncidentSubtypes;
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum IncidentDraftError {
+    /// Portal requires description of the incident
+    EmptyText,
+    /// Catalogue isn't empty, so subtype must be chosen
+    NoSubtype,
+    /// Subtype with such id isn't in the catalogue
+    UnknownSubtype(u32),
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct IncidentDraft {
+    /// Id of [`IncidentSubtype`](super::incident_subtypes::IncidentSubtype)
+    pub subtype:          Option<u32>,
+    /// Description of the incident
+    pub text:             String,
+    /// Internal order id of the related order, if any
+    pub related_order_id: Option<u32>,
+}
+
+impl IncidentDraft {
+    /// Checks draft against the catalogue of subtypes the same way incident
+    /// dialog does
+    pub fn validate(&self, subtypes: &IncidentSubtypes) -> Result<(), IncidentDraftError> {
+        if self.text.trim().is_empty() {
+            return Err(IncidentDraftError::EmptyText);
+        }
+
+        match self.subtype {
+            Some(subtype) if subtypes.get(subtype).is_none() => Err(IncidentDraftError::UnknownSubtype(subtype)),
+            None if !subtypes.0.is_empty() => Err(IncidentDraftError::NoSubtype),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn payload(&self, internal_order_id: u32) -> String {
+        let internal_order_id = internal_order_id.to_string();
+        let related_order_id = self.related_order_id.map(|id| id.to_string()).unwrap_or_default();
+        let subtype = self.subtype.map(|id| id.to_string()).unwrap_or_default();
+
+        serde_urlencoded::to_string([
+            ("action", "createIncident"),
+            ("id", &internal_order_id),
+            ("text", &self.text),
+            ("link", &related_order_id),
+            ("subtype", &subtype),
+        ])
+        .expect("Pairs of strings are always serializable")
+    }
+}
@@ -0,0 +1,81 @@
+//! Catalogue of incident subtypes
+//!
+//! Incident dialog on the view request page loads subtypes with
+//! `manualIncidentSubtypes` select query. Portal responds with `<option>`s
+//! grouped by incident type with `<optgroup>`s
+//!
+//! ## Example usage
+//! You can find example HTMLs in `src/tests/assets/incident_subtypes/valid`
+//!
+//! ```
+//! use nsg::incidents::incident_subtypes::IncidentSubtypes;
+//!
+//! let html = include_str!("../tests/assets/incident_subtypes/valid/1.html");
+//! let subtypes = IncidentSubtypes::from(&html);
+//!
+//! println!("Subtype: {:#?}", subtypes.find("Нет доступа в подъезд"));
+//! ```
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+/// Subtype which can be chosen for the new incident
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct IncidentSubtype {
+    /// Id used in `createIncident`
+    pub id:            u32,
+    pub name:          String,
+    /// Type the subtype belongs to, `None` if Portal didn't group subtypes
+    pub incident_type: Option<String>,
+}
+
+/// Parsed catalogue of incident subtypes. Options without valid id (like empty
+/// one) are skipped
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct IncidentSubtypes(pub Vec<IncidentSubtype>);
+
+impl IncidentSubtypes {
+    /// Parse subtypes from `manualIncidentSubtypes` select query HTML
+    pub fn from(html: &str) -> IncidentSubtypes {
+        let fragment = Html::parse_fragment(&format!("<select>{html}</select>"));
+        let selector = Selector::parse("option").unwrap();
+
+        IncidentSubtypes(
+            fragment
+                .select(&selector)
+                .filter_map(|option| {
+                    let id = u32::from_str(option.attr("value")?.trim()).ok()?;
+                    let incident_type = option
+                        .parent()
+                        .and_then(ElementRef::wrap)
+                        .filter(|parent| parent.value().name() == "optgroup")
+                        .and_then(|optgroup| optgroup.attr("label"))
+                        .map(|label| label.trim().to_string());
+
+                    Some(IncidentSubtype {
+                        id,
+                        name: option.text().collect_vec().join(" ").trim().to_string(),
+                        incident_type,
+                    })
+                })
+                .collect_vec(),
+        )
+    }
+
+    pub fn get(&self, id: u32) -> Option<&IncidentSubtype> { self.0.iter().find(|subtype| subtype.id == id) }
+
+    /// Finds subtype by its name, like "Нет доступа в подъезд"
+    pub fn find(&self, name: &str) -> Option<&IncidentSubtype> { self.0.iter().find(|subtype| subtype.name == name) }
+
+    /// Distinct incident types in order of appearance
+    pub fn incident_types(&self) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter_map(|subtype| subtype.incident_type.as_deref())
+            .unique()
+            .collect_vec()
+    }
+}
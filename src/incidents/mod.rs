@@ -0,0 +1,59 @@
+//! Incidents parser (collection of [`Incident`]s)
+//!
+//! View request page loads incidents of the order with `rq_incidents` table
+//! query. Columns are looked up by their headers: "№", "Тип", "Подтип",
+//! "Статус", "Описание", "Создан", "Обновлен" and "Автор"
+//!
+//! ## Example usage
+//! You can find example HTMLs in `src/tests/assets/incidents/valid`
+//!
+//! ```
+//! use nsg::incidents::Incidents;
+//!
+//! let html = include_str!("../tests/assets/incidents/valid/1.html");
+//! let incidents = Incidents::from(&html);
+//!
+//! println!("Incidents: {:#?}", incidents.0);
+//! ```
+
+pub mod incident;
+pub mod incident_draft;
+pub mod incident_subtypes;
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use self::incident::Incident;
+use crate::data::full_comment::FullComment;
+use crate::table_query::table_row::TableRow;
+use crate::table_query::Table;
+
+/// Parsed incidents containing vector of [`Incident`]s
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Default, Deserialize)]
+pub struct Incidents(pub Vec<Incident>);
+
+impl Incidents {
+    /// Parse incidents from `rq_incidents` table query HTML
+    pub fn from(html: &str) -> Incidents {
+        Incidents(Table::from(html).rows.iter().map(Incidents::parse_row).collect_vec())
+    }
+
+    fn parse_row(row: &TableRow) -> Incident {
+        let text = |column| row.get_non_empty(column).map(str::to_string);
+
+        Incident {
+            id:            row
+                .get_non_empty("№")
+                .map(|id| u32::from_str(id).map_err(|err| (*err.kind()).into())),
+            incident_type: text("Тип"),
+            subtype:       text("Подтип"),
+            status:        text("Статус"),
+            description:   text("Описание"),
+            created:       row.get_non_empty("Создан").map(FullComment::as_datetime),
+            updated:       row.get_non_empty("Обновлен").map(FullComment::as_datetime),
+            author:        text("Автор"),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Inventory operation of the order
+
+use std::str::FromStr;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum InventoryDirectionError {
+    /// Provided `&str` didn't match any direction and thus can't be
+    /// represented as [`InventoryDirection`]
+    InvalidDirection(String),
+}
+
+/// Whether equipment came to client or back to the stock
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub enum InventoryDirection {
+    /// Installed at client's place
+    Installed,
+    /// Taken back from client's place
+    Dismantled,
+    /// Used up, like cable or connectors
+    WrittenOff,
+}
+
+impl FromStr for InventoryDirection {
+    type Err = InventoryDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Установка" | "Установлено" => Ok(InventoryDirection::Installed),
+            "Демонтаж" | "Демонтировано" => Ok(InventoryDirection::Dismantled),
+            "Списание" | "Списано" => Ok(InventoryDirection::WrittenOff),
+            _ => Err(InventoryDirectionError::InvalidDirection(s.to_string())),
+        }
+    }
+}
+
+/// Parsed inventory operation of the order. For detailed information about
+/// field refer to it's documentation. Note that all fields will not fail hard
+/// allowing to work with partially valid operations
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct InventoryOperation {
+    /// Name of equipment or material, like "Кабель UTP"
+    pub item:      Option<String>,
+    /// Serial number, only for equipment
+    pub serial:    Option<String>,
+    pub quantity:  Option<Result<u32, SerializableIntErrorKind>>,
+    pub direction: Option<Result<InventoryDirection, InventoryDirectionError>>,
+    pub datetime:  Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// Full name of user who performed the operation
+    pub user:      Option<String>,
+}
+
+/// Hence [`InventoryOperation`] will not fail hard, it's not necessary valid.
+/// You can guarantee validness of operation with
+/// [`InventoryOperation::into_guaranteed`]. For detailed information about
+/// field refer to it's documentation or [`InventoryOperation`]'s documentation
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct GuaranteedInventoryOperation {
+    pub item:      String,
+    pub serial:    Option<String>,
+    pub quantity:  u32,
+    pub direction: InventoryDirection,
+    pub datetime:  DateTime<FixedOffset>,
+    pub user:      String,
+}
+
+impl InventoryOperation {
+    pub fn into_guaranteed(self) -> Option<GuaranteedInventoryOperation> {
+        Some(GuaranteedInventoryOperation {
+            item:      self.item?,
+            serial:    self.serial,
+            quantity:  self.quantity?.ok()?,
+            direction: self.direction?.ok()?,
+            datetime:  self.datetime?.ok()?,
+            user:      self.user?,
+        })
+    }
+}
@@ -0,0 +1,71 @@
+//! Inventory operations parser (collection of [`InventoryOperation`]s)
+//!
+//! View request page loads equipment installed on or written off the order
+//! with `request_invops` JSON table query. Portal responds with JSON array of
+//! operations. Only the keys `item`, `serial`, `quantity`, `direction`,
+//! `datetime` and `user` are read
+//!
+//! **Experimental**: the example JSONs aren't captured from Portal, so the
+//! keys may not match the real response
+//!
+//! ## Example usage
+//! You can find example JSONs in `src/tests/assets/inventory_operations/valid`
+//!
+//! ```
+//! use nsg::inventory_operations::InventoryOperations;
+//!
+//! let json = include_str!("../tests/assets/inventory_operations/valid/1.json");
+//! let inventory_operations = InventoryOperations::from(&json);
+//!
+//! println!("Inventory operations: {:#?}", inventory_operations.0);
+//! ```
+
+pub mod inventory_operation;
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use self::inventory_operation::{InventoryDirection, InventoryOperation};
+use crate::data::full_comment::FullComment;
+use crate::traits::json_value::JsonValue;
+
+/// Parsed inventory operations containing vector of [`InventoryOperation`]s
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Default, Deserialize)]
+pub struct InventoryOperations(pub Vec<InventoryOperation>);
+
+impl InventoryOperations {
+    /// Parse inventory operations from `request_invops` JSON
+    pub fn from(json: &str) -> InventoryOperations {
+        log::debug!(target: "nsg", "Processing JSON: {:?}", json);
+
+        let operations = match serde_json::from_str::<Vec<Value>>(json) {
+            Ok(operations) => operations,
+            Err(err) => {
+                log::warn!(target: "nsg", "Inventory operations are not a valid JSON array: {:?}", err);
+                return InventoryOperations::default();
+            },
+        };
+
+        InventoryOperations(
+            operations
+                .iter()
+                .map(|operation| InventoryOperation {
+                    item:      operation.get_string("item"),
+                    serial:    operation.get_string("serial"),
+                    quantity:  operation
+                        .get_string("quantity")
+                        .map(|quantity| u32::from_str(&quantity).map_err(|err| (*err.kind()).into())),
+                    direction: operation
+                        .get_string("direction")
+                        .map(|direction| InventoryDirection::from_str(&direction)),
+                    datetime:  operation
+                        .get_string("datetime")
+                        .map(|datetime| FullComment::as_datetime(&datetime)),
+                    user:      operation.get_string("user").map(|user| user.trim().to_string()),
+                })
+                .collect(),
+        )
+    }
+}
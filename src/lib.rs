@@ -1,9 +1,16 @@
 //! Nsg is a library for interacting with Portal.
 //! It provides read-only methods for basic search, view request, work schedule
-//! and brief request
+//! and brief request. A few write actions are available too: adding a comment
+//! with [`Nsg::add_comment`], creating an incident with
+//! [`Nsg::create_incident`], confirming SBMS changes with
+//! [`Nsg::confirm_sbms_change`] and rolling back orders with
+//! [`Nsg::rollback_request`]. They can be tried out safely in
+//! [dry run](builder::NsgBuilder::dry_run) mode
 //!
 //! **Note**: This library is written to work only with users with the role of
-//! "Монтажник (все)" It may not work properly with other roles
+//! "Монтажник (все)" It may not work properly with other roles. You can check
+//! the role with [`Nsg::user`] and
+//! [`User::is_installer`](user::User::is_installer)
 //!
 //! ```
 //! use chrono::Utc;
@@ -21,7 +28,7 @@
 //! .await?;
 //!
 //! // Fetch orders from Work Schedule for today
-//! let work_schedule = nsg.work_schedule(Utc::now().date_naive()).await;
+//! let work_schedule = nsg.work_schedule(Utc::now().date_naive()).await?;
 //! println!("{:?}", work_schedule.0);
 //!
 //! # Ok::<(), NsgError>(())
@@ -35,10 +42,10 @@
 //!   order in its database, thus creating an internal identifier for the
 //!   request
 //! - **Data pillar**: Portal's endpoint or the way to access data from Portal.
-//!   There are 4 data pillars: [`BasicSearch`](basic_search::BasicSearch),
+//!   For example: [`BasicSearch`](basic_search::BasicSearch),
 //!   [`BriefRequest`](brief_request::BriefRequest),
-//!   [`ViewRequest`](view_request::ViewRequest) and
-//!   [`WorkSchedule`](work_schedule::WorkSchedule)
+//!   [`ViewRequest`](view_request::ViewRequest),
+//!   [`WorkSchedule`](work_schedule::WorkSchedule) and [`User`](user::User)
 //! - **Portal**: Refers to "Нет-Строй"'s "IC Портал-К"
 //! - **Kyivstar's version**: Kyivstar's data is not always 100% right. It's
 //!   really apparent in the client's full name
@@ -46,20 +53,39 @@
 // TODO: Test README.md's code examples
 // #[doc = include_str!("../README.md")]
 
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+
+use builder::{NsgBuilder, RetryPolicy};
 use error::{NsgError, NsgResult};
-use payload::LoginPayload;
+use payload::{LoginPayload, RequestBody};
+use rate_limit::RateLimiter;
 use reqwest::header::HeaderMap;
 use reqwest::Client;
 
 pub mod basic_search;
 pub mod brief_request;
 pub mod data;
+pub mod incidents;
+pub mod inventory_operations;
+pub mod new_comment;
+pub mod on_demand_info;
+pub mod request_history;
+pub mod request_instances;
+pub mod router_check;
+pub mod sbms_history;
+pub mod service_hint;
+pub mod staircases;
+pub mod table_query;
+pub mod user;
 pub mod view_request;
 pub mod work_schedule;
 
+pub mod builder;
 pub mod error;
 #[doc(hidden)]
 pub mod methods;
+pub mod rate_limit;
 
 pub mod serializable_int_error_kind;
 pub mod serializable_parse_error_kind;
@@ -74,12 +100,20 @@ mod tests;
 /// Wrapper around data pillar parsers and Portal
 #[derive(Clone, Debug)]
 pub struct Nsg {
-    /// Used session cookie value to interact with Portal
-    pub session:     Option<String>,
+    /// Used session cookie value to interact with Portal. Shared between
+    /// clones, so relogin performed by one of them is visible to the others
+    session:         Arc<RwLock<Option<String>>>,
+    /// Held while relogin is performed, so concurrent requests that found
+    /// session expired share a single relogin
+    relogin_lock:    Arc<tokio::sync::Mutex<()>>,
     client:          Client,
     root:            String,
     http_client:     String,
     http_client_ver: String,
+    retry_policy:    RetryPolicy,
+    rate_limiter:    Option<Arc<RateLimiter>>,
+    /// See [`NsgBuilder::dry_run`]
+    dry_run:         bool,
 
     login:         Option<String>,
     password_hash: Option<String>,
@@ -105,7 +139,7 @@ impl Nsg {
     /// )
     /// .await?;
     ///
-    /// println!("Session: {:?}", nsg.session);
+    /// println!("Session: {:?}", nsg.session());
     ///
     /// # Ok::<(), NsgError>(())
     /// # });
@@ -117,22 +151,9 @@ impl Nsg {
         http_client: String,
         http_client_ver: String,
     ) -> Result<Nsg, NsgError> {
-        let mut nsg = Nsg {
-            client: Client::builder().build().unwrap(),
-            session: None,
-            root,
-            http_client,
-            http_client_ver,
-
-            login: Some(login),
-            password_hash: Some(password_hash),
-        };
-
-        nsg.relogin()
+        NsgBuilder::new(root, http_client, http_client_ver)
+            .build_with_creds(login, password_hash)
             .await
-            .map_err(|err| NsgError::FailedToRelogin(Box::new(err)))?;
-
-        Ok(nsg)
     }
 
     /// If you already have a session, you can create an instance to use this
@@ -140,7 +161,8 @@ impl Nsg {
     ///
     /// **Note**: You can't use [Nsg::relogin] because it requires
     /// login and password hash to be set. If you have login and password hash
-    /// simply use [Nsg::from_creds]
+    /// simply use [Nsg::from_creds]. Therefore once the session expires bridge
+    /// methods will return [NsgError::SessionExpired]
     ///
     /// ```
     /// use nsg::Nsg;
@@ -153,24 +175,20 @@ impl Nsg {
     ///     "client".to_string(),
     ///     "x.y".to_string(),
     /// )
-    /// .await;
+    /// .await?;
     ///
     /// println!("Is session alive?: {}", nsg.is_session_alive().await?);
     ///
     /// # Ok::<(), NsgError>(())
     /// # });
     /// ```
-    pub async fn from_session(session: String, root: String, http_client: String, http_client_ver: String) -> Nsg {
-        Nsg {
-            client: Client::builder().build().unwrap(),
-            session: Some(session),
-            root,
-            http_client,
-            http_client_ver,
-
-            login: None,
-            password_hash: None,
-        }
+    pub async fn from_session(
+        session: String,
+        root: String,
+        http_client: String,
+        http_client_ver: String,
+    ) -> Result<Nsg, NsgError> {
+        NsgBuilder::new(root, http_client, http_client_ver).build_with_session(session)
     }
 
     /// Perform request to get new session cookie.
@@ -179,15 +197,17 @@ impl Nsg {
     /// relogin you can check validness of session via
     /// [Nsg::is_session_alive]
     ///
-    /// Panics if [Nsg::relogin] called on instance created with
-    /// [Nsg::from_session] i.e. login or password hash is not set
+    /// Fails with [NsgError::SessionExpired] if called on instance created
+    /// with [Nsg::from_session] i.e. login or password hash is not set.
+    /// Takes `&self` instead of `&mut self` as it used to, since new session is
+    /// shared with all clones of the instance
     ///
     /// ```
     /// use nsg::Nsg;
     /// # use nsg::error::NsgError;
     ///
     /// # tokio_test::block_on(async {
-    /// let mut nsg = Nsg::from_creds(
+    /// let nsg = Nsg::from_creds(
     ///     "login".to_string(),
     ///     "password_hash".to_string(),
     ///     "https://net-stroy.itnet.lviv.ua".to_string(),
@@ -196,24 +216,26 @@ impl Nsg {
     /// )
     /// .await?;
     ///
-    /// println!("Session (before): {:?}", nsg.session);
+    /// println!("Session (before): {:?}", nsg.session());
     /// nsg.relogin().await?;
-    /// println!("Session (after): {:?}", nsg.session);
+    /// println!("Session (after): {:?}", nsg.session());
     ///
     /// # Ok::<(), NsgError>(())
     /// # });
     /// ```
-    pub async fn relogin(&mut self) -> NsgResult<()> {
+    pub async fn relogin(&self) -> NsgResult<()> {
+        let (Some(login), Some(password_hash)) = (&self.login, &self.password_hash) else {
+            return Err(NsgError::SessionExpired);
+        };
+
         let headers = self.construct_headers();
-        let payload = Nsg::construct_login_payload(
-            self.login.as_ref().expect("No login"),
-            self.password_hash.as_ref().expect("No password hash"),
-        )?;
+        let payload = Nsg::construct_login_payload(login, password_hash)?;
 
         let response = self.request(headers, payload, None).await?;
 
         let session_cookie = response.cookies().next().ok_or(NsgError::NoSessionCookie)?;
-        self.session = Some(session_cookie.value().to_string());
+        *self.session.write().expect("Session lock should never be poisoned") =
+            Some(session_cookie.value().to_string());
 
         Ok(())
     }
@@ -235,13 +257,28 @@ impl Nsg {
                 .unwrap(),
         );
 
-        if let Some(session) = &self.session {
+        if let Some(session) = self.session() {
             headers.insert("cookie", format!("SESSION_nsg={}", session).parse().unwrap());
         }
 
         headers
     }
 
+    /// Whether write actions are only logged, see [`NsgBuilder::dry_run`]
+    pub fn is_dry_run(&self) -> bool { self.dry_run }
+
+    /// Currently used session cookie value.
+    ///
+    /// **Note**: Session used to be a public field. Since it's shared between
+    /// clones and may be renewed by any of them, it's available only via this
+    /// method now
+    pub fn session(&self) -> Option<String> {
+        self.session
+            .read()
+            .expect("Session lock should never be poisoned")
+            .clone()
+    }
+
     fn construct_login_payload(login: &str, password_hash: &str) -> Result<String, NsgError> {
         let payload = LoginPayload {
             action: "user/login",
@@ -257,7 +294,11 @@ impl Nsg {
 
     /// Makes an arbitrary POST request to Portal. Url should be relative to
     /// root and shouldn't start with `/`. For example `headless.php`, not
-    /// `/headless.php`. If `url` is `None`, then `headless.php` is used
+    /// `/headless.php`. If `url` is `None`, then `headless.php` is used.
+    /// Failed request is retried according to [`RetryPolicy`] set with
+    /// [`NsgBuilder::retry_policy`]. Every attempt waits for
+    /// [`RateLimit`](rate_limit::RateLimit) set with
+    /// [`NsgBuilder::rate_limit`]
     ///
     /// ```
     /// use nsg::Nsg;
@@ -289,20 +330,76 @@ impl Nsg {
         payload: String,
         url: Option<&str>,
     ) -> Result<reqwest::Response, NsgError> {
+        self.request_body(
+            headers,
+            &RequestBody::UrlEncoded(payload),
+            url,
+            &self.retry_policy,
+            |response| async { Ok(response) },
+        )
+        .await
+    }
+
+    /// Same as [Nsg::request], but for any kind of body. Response is passed to
+    /// `read` while the request still counts as in flight for
+    /// [`RateLimit::max_in_flight`](rate_limit::RateLimit::max_in_flight), so
+    /// reading of the body is limited too. Retryable errors returned by `read`
+    /// are retried the same way as failed requests according to
+    /// `retry_policy`
+    async fn request_body<T, F, Fut>(
+        &self,
+        headers: HeaderMap,
+        body: &RequestBody,
+        url: Option<&str>,
+        retry_policy: &RetryPolicy,
+        read: F,
+    ) -> NsgResult<T>
+    where
+        F: Fn(reqwest::Response) -> Fut,
+        Fut: Future<Output = NsgResult<T>>,
+    {
         let request_url = match url {
             Some(url) => format!("{}/{}", self.root, url),
             None => format!("{}/headless.php", self.root),
         };
 
-        let response = self
-            .client
-            .request(reqwest::Method::POST, request_url)
-            .headers(headers)
-            .body(payload)
-            .send()
-            .await?;
+        let mut attempt = 0;
+
+        loop {
+            let permit = match &self.rate_limiter {
+                Some(rate_limiter) => Some(rate_limiter.acquire().await),
+                None => None,
+            };
 
-        Ok(response)
+            let builder = body.apply(
+                self.client.request(reqwest::Method::POST, &request_url),
+                headers.clone(),
+            )?;
+            let result = match builder.send().await {
+                Ok(response) => read(response).await,
+                Err(err) => Err(NsgError::from(err)),
+            };
+
+            // Sleeping retry isn't in flight and shouldn't block others
+            drop(permit);
+
+            match result {
+                Err(err) if err.is_retryable() && attempt < retry_policy.max_retries => {
+                    let backoff = retry_policy.backoff(attempt);
+                    log::warn!(
+                        target: "nsg",
+                        "Request to {} failed with {:?}, retrying in {:?}...",
+                        request_url,
+                        err,
+                        backoff
+                    );
+
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                },
+                result => return result,
+            }
+        }
     }
 
     /// Performs empty request to check [current session](`Nsg::session`)
@@ -328,10 +425,121 @@ impl Nsg {
     /// # });
     /// ```
     pub async fn is_session_alive(&self) -> Result<bool, NsgError> {
-        let response = self.request(self.construct_headers(), String::from(""), None).await?;
+        let body = self.request_text(String::from(""), None).await?;
 
-        let body = response.text().await.unwrap();
+        Ok(!Nsg::is_login_page(&body))
+    }
+
+    fn is_login_page(body: &str) -> bool { body.contains("logoform") }
+
+    /// Same as [Nsg::request], but uses [Nsg::construct_headers], fails on
+    /// non-2xx status code and returns body as UTF-8 text
+    pub(crate) async fn request_text(&self, body: impl Into<RequestBody>, url: Option<&str>) -> NsgResult<String> {
+        self.request_text_with(body.into(), url, &self.retry_policy).await
+    }
+
+    async fn request_text_with(
+        &self,
+        body: RequestBody,
+        url: Option<&str>,
+        retry_policy: &RetryPolicy,
+    ) -> NsgResult<String> {
+        self.request_body(self.construct_headers(), &body, url, retry_policy, Nsg::read_text)
+            .await
+    }
 
-        Ok(!body.contains("logoform"))
+    async fn read_text(response: reqwest::Response) -> NsgResult<String> {
+        let status = response.status();
+        if !status.is_success() {
+            return Err(NsgError::UnsuccessfulStatus(status.as_u16()));
+        }
+
+        let bytes = response.bytes().await?;
+
+        String::from_utf8(bytes.to_vec()).map_err(|_| NsgError::InvalidUtf8Body)
+    }
+
+    /// Same as [Nsg::request_text], but detects expired session (Portal
+    /// responds with login page). In such case performs [Nsg::relogin] once
+    /// and retries the request. Instances created with [Nsg::from_session]
+    /// can't relogin and fail with [NsgError::SessionExpired] instead
+    pub(crate) async fn request_authorized(
+        &self,
+        body: impl Into<RequestBody>,
+        url: Option<&str>,
+    ) -> NsgResult<String> {
+        self.request_authorized_with(body.into(), url, &self.retry_policy).await
+    }
+
+    async fn request_authorized_with(
+        &self,
+        body: RequestBody,
+        url: Option<&str>,
+        retry_policy: &RetryPolicy,
+    ) -> NsgResult<String> {
+        let session = self.session();
+        let text = self.request_text_with(body.clone(), url, retry_policy).await?;
+
+        if !Nsg::is_login_page(&text) {
+            return Ok(text);
+        }
+
+        if self.login.is_none() || self.password_hash.is_none() {
+            return Err(NsgError::SessionExpired);
+        }
+
+        self.relogin_expired(session)
+            .await
+            .map_err(|err| NsgError::FailedToRelogin(Box::new(err)))?;
+
+        let text = self.request_text_with(body, url, retry_policy).await?;
+
+        if Nsg::is_login_page(&text) {
+            return Err(NsgError::SessionExpired);
+        }
+
+        Ok(text)
+    }
+
+    /// Performs [Nsg::relogin] unless session has already been renewed since
+    /// `expired` was used, e.g. by concurrent request that found it expired
+    /// too
+    async fn relogin_expired(&self, expired: Option<String>) -> NsgResult<()> {
+        let _guard = self.relogin_lock.lock().await;
+
+        if self.session() != expired {
+            return Ok(());
+        }
+
+        log::warn!(target: "nsg", "Session expired, performing relogin...");
+        self.relogin().await
+    }
+
+    /// Same as [Nsg::request_authorized], but for actions changing data on
+    /// Portal. In [dry run](NsgBuilder::dry_run) mode logs the payload and
+    /// returns `None` instead of sending it. Failed request isn't retried, as
+    /// Portal may have already performed the action, e.g. if response timed
+    /// out. Request rejected with login page is still repeated after relogin
+    pub(crate) async fn request_write(
+        &self,
+        body: impl Into<RequestBody>,
+        url: Option<&str>,
+    ) -> NsgResult<Option<String>> {
+        let body = body.into();
+
+        if self.dry_run {
+            log::info!(
+                target: "nsg",
+                "Dry run, not sending request to {}: {}",
+                url.unwrap_or("headless.php"),
+                body.describe()
+            );
+
+            return Ok(None);
+        }
+
+        self.request_authorized_with(body, url, &RetryPolicy::default())
+            .await
+            .map(Some)
     }
 }
@@ -1,59 +1,212 @@
+use futures::stream::{self, StreamExt};
+use serde::de::DeserializeOwned;
+
 use crate::basic_search::BasicSearch;
 use crate::brief_request::BriefRequest;
+use crate::data::full_comment::FullComment;
+use crate::error::{NsgError, NsgResult};
+use crate::incidents::incident_draft::IncidentDraft;
+use crate::incidents::incident_subtypes::IncidentSubtypes;
+use crate::incidents::Incidents;
+use crate::inventory_operations::InventoryOperations;
+use crate::new_comment::NewComment;
+use crate::on_demand_info::OnDemandInfo;
 use crate::payload::Payload;
+use crate::request_history::field_change::FieldChange;
+use crate::request_history::OrderHistory;
+use crate::request_instances::RequestInstances;
+use crate::router_check::RouterCheck;
+use crate::sbms_history::SbmsHistory;
+use crate::service_hint::ServiceHint;
+use crate::staircases::Staircases;
+use crate::table_query::Table;
+use crate::user::User;
 use crate::view_request::ViewRequest;
+use crate::work_schedule::detailed::{DetailOptions, DetailedOrder};
+use crate::work_schedule::order::Order;
 use crate::work_schedule::WorkSchedule;
 use crate::Nsg;
 
 /// Implementation for bridge methods between Portal and data pillar parsers
 impl Nsg {
-    pub async fn work_schedule(&self, date: chrono::NaiveDate) -> WorkSchedule {
+    pub async fn work_schedule(&self, date: chrono::NaiveDate) -> NsgResult<WorkSchedule> {
         let payload = serde_urlencoded::to_string(Payload {
             action: "workschedule1",
             city:   "",
             data:   &date.format("%Y-%m-%d").to_string(),
         })
-        .unwrap();
+        .map_err(|_| NsgError::UrlEncodeError)?;
+
+        let response = self.request_authorized(payload, None).await?;
+
+        Ok(WorkSchedule::from(&response))
+    }
+
+    /// Fetches work schedule and then data pillars chosen in `options` for
+    /// every order concurrently. Orders are returned in the same order as in
+    /// work schedule
+    pub async fn work_schedule_detailed(
+        &self,
+        date: chrono::NaiveDate,
+        options: DetailOptions,
+    ) -> NsgResult<Vec<DetailedOrder>> {
+        let work_schedule = self.work_schedule(date).await?;
+
+        let detailed_orders = stream::iter(work_schedule.0)
+            .map(|order| self.detail_order(order, &options))
+            .buffered(options.concurrency.max(1))
+            .collect()
+            .await;
+
+        Ok(detailed_orders)
+    }
+
+    pub(crate) async fn detail_order(&self, order: Order, options: &DetailOptions) -> DetailedOrder {
+        let internal_order_id = match order.internal_order_id {
+            Some(Ok(internal_order_id)) => Ok(internal_order_id),
+            _ => Err(NsgError::NoInternalOrderId),
+        };
+
+        let view_request = async {
+            if !options.view_request {
+                return None;
+            }
+
+            Some(match internal_order_id.clone() {
+                Ok(internal_order_id) => self.view_request(internal_order_id).await,
+                Err(err) => Err(err),
+            })
+        };
+        let brief_request = async {
+            if !options.brief_request {
+                return None;
+            }
+
+            Some(match internal_order_id.clone() {
+                Ok(internal_order_id) => self.brief_request(internal_order_id).await,
+                Err(err) => Err(err),
+            })
+        };
+
+        let (view_request, brief_request) = futures::join!(view_request, brief_request);
+
+        if let Some(Err(err)) = &view_request {
+            log::warn!(target: "nsg", "Failed to fetch view request of {:?}: {:?}", order.internal_order_id, err);
+        }
+        if let Some(Err(err)) = &brief_request {
+            log::warn!(target: "nsg", "Failed to fetch brief request of {:?}: {:?}", order.internal_order_id, err);
+        }
+
+        DetailedOrder {
+            order,
+            view_request,
+            brief_request,
+        }
+    }
+
+    pub async fn brief_request(&self, internal_order_id: u32) -> NsgResult<BriefRequest> {
+        let text = self
+            .request_authorized(format!("action=briefRequest&id={internal_order_id}&hold=0"), None)
+            .await?;
+
+        Ok(BriefRequest::from(&text))
+    }
+
+    pub async fn view_request(&self, internal_order_id: u32) -> NsgResult<ViewRequest> {
+        let response = self.view_request_html(internal_order_id).await?;
+
+        Ok(ViewRequest::from(&response))
+    }
+
+    /// Adds comment to the order, optionally attaching a file. Then fetches
+    /// view request to make sure the comment has appeared and returns it. The
+    /// comment is looked up by text among comments that weren't there before
+    /// it was sent. Returns `None` in
+    /// [dry run](crate::builder::NsgBuilder::dry_run) mode
+    ///
+    /// ```
+    /// use nsg::new_comment::NewComment;
+    /// use nsg::Nsg;
+    /// # use nsg::error::NsgError;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let nsg = Nsg::from_creds(
+    ///     "login".to_string(),
+    ///     "password_hash".to_string(),
+    ///     "https://net-stroy.itnet.lviv.ua".to_string(),
+    ///     "client".to_string(),
+    ///     "x.y".to_string(),
+    /// )
+    /// .await?;
+    ///
+    /// let comment = nsg
+    ///     .add_comment(950207, NewComment {
+    ///         text:       "Полностью заменил кабель".to_string(),
+    ///         attachment: None,
+    ///     })
+    ///     .await?;
+    /// println!("Added: {:?}", comment);
+    ///
+    /// # Ok::<(), NsgError>(())
+    /// # });
+    /// ```
+    pub async fn add_comment(&self, internal_order_id: u32, comment: NewComment) -> NsgResult<Option<FullComment>> {
+        let before = if self.is_dry_run() {
+            Vec::new()
+        } else {
+            self.view_request(internal_order_id).await?.comments
+        };
 
         let response = self
-            .request(self.construct_headers(), payload, None)
-            .await
-            .unwrap()
-            .text()
-            .await
-            .unwrap();
+            .request_write(
+                comment.body(internal_order_id),
+                Some("headless.php?action=addRequestComment"),
+            )
+            .await?;
 
-        WorkSchedule::from(&response)
+        if response.is_none() {
+            return Ok(None);
+        }
+
+        let view_request = self.view_request(internal_order_id).await?;
+
+        comment
+            .find_new(
+                before.into_iter().flatten().collect(),
+                view_request.comments.into_iter().flatten().collect(),
+            )
+            .map(Some)
+            .ok_or(NsgError::CommentNotFound)
     }
 
-    pub async fn brief_request(&self, internal_order_id: u32) -> BriefRequest {
+    /// Fetches view request page and parses order history from it
+    pub async fn request_history(&self, internal_order_id: u32) -> NsgResult<OrderHistory> {
+        let response = self.view_request_html(internal_order_id).await?;
+
+        Ok(OrderHistory::from(&response))
+    }
+
+    /// Fetches all fields changed with history entry. Id of the entry can be
+    /// found in [`HistoryEntry::id`](crate::request_history::history_entry::HistoryEntry::id)
+    pub async fn request_history_entry(&self, history_entry_id: u32) -> NsgResult<Vec<FieldChange>> {
         let text = self
-            .request(
-                self.construct_headers(),
-                format!("action=briefRequest&id={internal_order_id}&hold=0"),
-                None,
+            .request_authorized(
+                format!("action=viewRequestHistory&id={history_entry_id}"),
+                Some("headless.php?action=viewRequestHistory"),
             )
-            .await
-            .unwrap()
-            .text()
-            .await
-            .unwrap();
+            .await?;
 
-        BriefRequest::from(&text)
+        Ok(FieldChange::from_table(&text))
     }
 
-    pub async fn view_request(&self, internal_order_id: u32) -> ViewRequest {
+    /// View request is accessible only with session code from its page
+    async fn view_request_html(&self, internal_order_id: u32) -> NsgResult<String> {
         let session_code_body = self
-            .request(
-                self.construct_headers(),
+            .request_authorized(
                 "".to_string(),
-                Some(&format!("/index.php?action=viewRequest&id={internal_order_id}")),
+                Some(&format!("index.php?action=viewRequest&id={internal_order_id}")),
             )
-            .await
-            .unwrap()
-            .text()
-            .await
-            .unwrap();
+            .await?;
 
         let mut session_code = None;
 
@@ -64,48 +217,331 @@ impl Nsg {
             }
         }
 
-        let response = self
-            .request(
-                self.construct_headers(),
-                "".to_string(),
-                Some(&format!("/session.php?code={}", session_code.unwrap())),
-            )
-            .await
-            .unwrap()
-            .text()
+        let session_code = session_code.ok_or(NsgError::NoSessionCode)?;
+
+        self.request_authorized("".to_string(), Some(&format!("session.php?code={session_code}")))
             .await
-            .unwrap();
+    }
+
+    /// Fetches and parses staircases of the order's building. Address id is
+    /// taken from the view request, see [`Nsg::staircases_by_address`] if you
+    /// already know it
+    pub async fn staircases(&self, internal_order_id: u32) -> NsgResult<Staircases> {
+        let view_request = ViewRequest::from(&self.view_request_html(internal_order_id).await?);
+
+        let address_id = match view_request.address_id {
+            Some(Ok(address_id)) => address_id,
+            _ => return Err(NsgError::NoAddressId),
+        };
+
+        let mut staircases = self.staircases_by_address(address_id).await?;
+        staircases.address = view_request.address;
 
-        ViewRequest::from(&response)
+        Ok(staircases)
     }
 
-    // TODO: Provide User data pillar
-    // /// Fetches and parses the user
-    // pub async fn get_user(&self) -> serde_json::Value {
-    //     let text = self
-    //         .request(self.construct_headers(),
-    // String::from("actiondata=core_getUser"), None)         .await
-    //         .unwrap()
-    //         .text()
-    //         .await
-    //         .unwrap();
+    /// Fetches and parses staircases of the building by Portal's address id
+    /// (`addrid`)
+    pub async fn staircases_by_address(&self, address_id: u32) -> NsgResult<Staircases> {
+        let address = address_id.to_string();
+        let vars = [("address", address.as_str())];
 
-    //     serde_json::from_str(&text).unwrap()
-    // }
+        let (staircases, comments) = futures::try_join!(
+            self.table_query_text("rq_staircases", &vars),
+            self.table_query_text("rq_staircasecomments", &vars),
+        )?;
 
-    pub async fn basic_search(&self, search_text: &str) -> BasicSearch {
+        Ok(Staircases::from(address_id, &staircases, &comments))
+    }
+
+    /// Fetches and parses incidents of the order
+    pub async fn incidents(&self, internal_order_id: u32) -> NsgResult<Incidents> {
         let text = self
-            .request(
-                self.construct_headers(),
-                format!("action=search_basic&searchtext={search_text}"),
-                None,
+            .table_query_text("rq_incidents", &[("request", &internal_order_id.to_string())])
+            .await?;
+
+        Ok(Incidents::from(&text))
+    }
+
+    /// Fetches catalogue of subtypes which can be chosen for a new incident
+    pub async fn incident_subtypes(&self) -> NsgResult<IncidentSubtypes> {
+        let text = self.table_query_text("manualIncidentSubtypes", &[]).await?;
+
+        Ok(IncidentSubtypes::from(&text))
+    }
+
+    /// Validates draft against [catalogue of subtypes](Nsg::incident_subtypes)
+    /// and creates incident of the order. Returns incidents of the order after
+    /// creation. In [dry run](crate::builder::NsgBuilder::dry_run) mode draft
+    /// is still validated
+    ///
+    /// ```
+    /// use nsg::incidents::incident_draft::IncidentDraft;
+    /// use nsg::Nsg;
+    /// # use nsg::error::NsgError;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let nsg = Nsg::from_creds(
+    ///     "login".to_string(),
+    ///     "password_hash".to_string(),
+    ///     "https://net-stroy.itnet.lviv.ua".to_string(),
+    ///     "client".to_string(),
+    ///     "x.y".to_string(),
+    /// )
+    /// .await?;
+    ///
+    /// let subtypes = nsg.incident_subtypes().await?;
+    /// let incidents = nsg
+    ///     .create_incident(950207, IncidentDraft {
+    ///         subtype:          subtypes
+    ///             .find("Нет доступа в подъезд")
+    ///             .map(|subtype| subtype.id),
+    ///         text:             "Домофон не работает".to_string(),
+    ///         related_order_id: None,
+    ///     })
+    ///     .await?;
+    /// println!("Incidents: {:#?}", incidents.0);
+    ///
+    /// # Ok::<(), NsgError>(())
+    /// # });
+    /// ```
+    pub async fn create_incident(&self, internal_order_id: u32, draft: IncidentDraft) -> NsgResult<Incidents> {
+        draft
+            .validate(&self.incident_subtypes().await?)
+            .map_err(NsgError::InvalidIncidentDraft)?;
+
+        if let Some(text) = self.request_write(draft.payload(internal_order_id), None).await? {
+            Nsg::check_action_error(&text)?;
+        }
+
+        self.incidents(internal_order_id).await
+    }
+
+    /// Confirms change imported from Kyivstar's system, see
+    /// [`SbmsEntry::needs_confirmation`](crate::sbms_history::sbms_entry::SbmsEntry::needs_confirmation).
+    /// `sbms_entry_id` is
+    /// [`SbmsEntry::id`](crate::sbms_history::sbms_entry::SbmsEntry::id),
+    /// not the order's id
+    pub async fn confirm_sbms_change(&self, sbms_entry_id: u32) -> NsgResult<()> {
+        let text = self
+            .request_write(
+                format!("action=confirmSBMSChange&id={sbms_entry_id}"),
+                Some("headless.php?action=confirmSBMSChange"),
             )
-            .await
-            .unwrap()
-            .text()
-            .await
-            .unwrap();
+            .await?;
+
+        match text {
+            Some(text) => Nsg::check_action_error(&text),
+            None => Ok(()),
+        }
+    }
+
+    /// Rolls the order back. Portal's rollback doesn't take a reason, so it's
+    /// recorded as a comment with [`Nsg::add_comment`] after successful
+    /// rollback. Blank reason isn't recorded. Failure to record the reason is
+    /// only logged, as the rollback itself has already been done and
+    /// shouldn't be repeated. This action is irreversible, consider testing
+    /// your integration in [dry run](crate::builder::NsgBuilder::dry_run) mode
+    /// first
+    ///
+    /// ```
+    /// use nsg::builder::NsgBuilder;
+    /// # use nsg::error::NsgError;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let nsg = NsgBuilder::new(
+    ///     "https://net-stroy.itnet.lviv.ua".to_string(),
+    ///     "client".to_string(),
+    ///     "x.y".to_string(),
+    /// )
+    /// .dry_run(true)
+    /// .build_with_creds("login".to_string(), "password_hash".to_string())
+    /// .await?;
+    ///
+    /// // Only logs the payload
+    /// nsg.rollback_request(950207, "Абонент передумал").await?;
+    ///
+    /// # Ok::<(), NsgError>(())
+    /// # });
+    /// ```
+    pub async fn rollback_request(&self, internal_order_id: u32, reason: &str) -> NsgResult<()> {
+        let text = self
+            .request_write(
+                format!("action=rollbackRequest&id={internal_order_id}"),
+                Some("headless.php?action=rollbackRequest"),
+            )
+            .await?;
+
+        if let Some(text) = text {
+            Nsg::check_action_error(&text)?;
+        }
+
+        if reason.trim().is_empty() {
+            return Ok(());
+        }
+
+        let comment = NewComment {
+            text:       reason.to_string(),
+            attachment: None,
+        };
+
+        if let Err(err) = self.add_comment(internal_order_id, comment).await {
+            log::error!(
+                target: "nsg",
+                "Order {} was rolled back, but its reason wasn't recorded: {:?}",
+                internal_order_id,
+                err
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Fetches and parses equipment and materials installed on or written off
+    /// the order
+    pub async fn inventory_operations(&self, internal_order_id: u32) -> NsgResult<InventoryOperations> {
+        let text = self
+            .table_query_text("request_invops", &[("id", &internal_order_id.to_string())])
+            .await?;
+
+        Ok(InventoryOperations::from(&text))
+    }
+
+    /// Fetches and parses instances of the order. Order reopened by Kyivstar
+    /// has several instances
+    pub async fn request_instances(&self, internal_order_id: u32) -> NsgResult<RequestInstances> {
+        let text = self
+            .table_query_text("request_getRequestInstances", &[("id", &internal_order_id.to_string())])
+            .await?;
+
+        Ok(RequestInstances::from(&text))
+    }
+
+    /// Fetches and parses SBMS history (Kyivstar's change log) of the order
+    pub async fn sbms_history(&self, internal_order_id: u32) -> NsgResult<SbmsHistory> {
+        let text = self
+            .table_query_text("rq_sbmslog", &[("request", &internal_order_id.to_string())])
+            .await?;
+
+        Ok(SbmsHistory::from(&text))
+    }
+
+    /// Fetches arbitrary `TableQuery` table by query name, like `rq_sbmslog`.
+    /// `params` are the `vars` of the query
+    ///
+    /// ```
+    /// use nsg::Nsg;
+    /// # use nsg::error::NsgError;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let nsg = Nsg::from_creds(
+    ///     "login".to_string(),
+    ///     "password_hash".to_string(),
+    ///     "https://net-stroy.itnet.lviv.ua".to_string(),
+    ///     "client".to_string(),
+    ///     "x.y".to_string(),
+    /// )
+    /// .await?;
+    ///
+    /// let staircases = nsg
+    ///     .table_query("rq_staircases", &[("address", "13879")])
+    ///     .await?;
+    /// println!("{:#?}", staircases.rows);
+    ///
+    /// # Ok::<(), NsgError>(())
+    /// # });
+    /// ```
+    pub async fn table_query(&self, name: &str, params: &[(&str, &str)]) -> NsgResult<Table> {
+        let text = self.table_query_text(name, params).await?;
 
-        BasicSearch::from(&text)
+        Ok(Table::from(&text))
+    }
+
+    /// Fetches arbitrary `JSONTableQuery` table by query name, like
+    /// `request_invops`, and deserializes its rows into `T`. `params` are the
+    /// `vars` of the query
+    pub async fn json_table_query<T: DeserializeOwned>(
+        &self,
+        name: &str,
+        params: &[(&str, &str)],
+    ) -> NsgResult<Vec<T>> {
+        let text = self.table_query_text(name, params).await?;
+
+        Table::deserialize_json(&text)
+    }
+
+    /// Both `TableQuery` and `JSONTableQuery` request data by its name
+    pub(crate) async fn table_query_text(&self, name: &str, params: &[(&str, &str)]) -> NsgResult<String> {
+        let payload = serde_urlencoded::to_string([("actiondata", name)].iter().chain(params).collect::<Vec<_>>())
+            .map_err(|_| NsgError::UrlEncodeError)?;
+
+        self.request_authorized(payload, None).await
+    }
+
+    /// Asks Kyivstar's system whether client's routers are activated. Check is
+    /// performed live, so it may take a while
+    pub async fn check_router(&self, internal_order_id: u32) -> NsgResult<RouterCheck> {
+        let text = self
+            .request_authorized(format!("action=checkSBMSRouter&request={internal_order_id}"), None)
+            .await?;
+
+        Ok(RouterCheck::from(&text))
+    }
+
+    /// Fetches details of the service, like speed and required equipment.
+    /// `service_id` is [`Service::id`](crate::data::service::Service::id) from
+    /// [`ViewRequest::services`]
+    pub async fn service_hint(&self, service_id: u32) -> NsgResult<ServiceHint> {
+        let payload = format!("action=getServiceHint&id={service_id}");
+
+        let html = self
+            .request_authorized(payload, Some("headless.php?action=getServiceHint"))
+            .await?;
+
+        Ok(ServiceHint::from(&html))
+    }
+
+    /// Asks Portal to update customer's data from Kyivstar's system. Same as
+    /// view request page does on every load
+    pub async fn on_demand_info(&self, internal_order_id: u32) -> NsgResult<OnDemandInfo> {
+        let text = self
+            .request_authorized(
+                format!("action=ondemandInfoRequest&id={internal_order_id}&cron=1"),
+                Some("headless.php?action=ondemandInfoRequest"),
+            )
+            .await?;
+
+        Ok(OnDemandInfo::from(&text))
+    }
+
+    /// Fetches and parses the user of the current session
+    pub async fn user(&self) -> NsgResult<User> {
+        let text = self
+            .request_authorized(String::from("actiondata=core_getUser"), None)
+            .await?;
+
+        Ok(User::from(&text))
+    }
+
+    pub async fn basic_search(&self, search_text: &str) -> NsgResult<BasicSearch> {
+        let text = self
+            .request_authorized(format!("action=search_basic&searchtext={search_text}"), None)
+            .await?;
+
+        Ok(BasicSearch::from(&text))
+    }
+
+    /// Portal reports refused write action with JSON like `{"error": "..."}`.
+    /// Any other response, including non-JSON one, means success
+    pub(crate) fn check_action_error(text: &str) -> NsgResult<()> {
+        let Ok(serde_json::Value::Object(response)) = serde_json::from_str::<serde_json::Value>(text) else {
+            return Ok(());
+        };
+
+        match response.get("error") {
+            Some(serde_json::Value::Null) | None => Ok(()),
+            Some(serde_json::Value::String(error)) => Err(NsgError::ActionFailed(error.clone())),
+            Some(error) => Err(NsgError::ActionFailed(error.to_string())),
+        }
     }
 }
@@ -0,0 +1,89 @@
+//! Comment to be added to the order with
+//! [`Nsg::add_comment`](crate::Nsg::add_comment)
+//!
+//! ## Example usage
+//!
+//! ```
+//! use nsg::new_comment::{Attachment, NewComment};
+//!
+//! let comment = NewComment {
+//!     text:       "Полностью заменил кабель".to_string(),
+//!     attachment: Some(Attachment {
+//!         file_name: "cable.jpg".to_string(),
+//!         mime:      Some("image/jpeg".to_string()),
+//!         content:   vec![0xFF, 0xD8, 0xFF],
+//!     }),
+//! };
+//! ```
+
+use itertools::Itertools;
+
+use crate::data::full_comment::FullComment;
+use crate::payload::{MultipartValue, RequestBody};
+
+/// File attached to the comment, usually a photo
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Attachment {
+    pub file_name: String,
+    /// Like `image/jpeg`. If `None`, Portal guesses it by itself
+    pub mime:      Option<String>,
+    pub content:   Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct NewComment {
+    /// Portal doesn't accept empty comments
+    pub text:       String,
+    /// Portal accepts only one file per comment
+    pub attachment: Option<Attachment>,
+}
+
+impl NewComment {
+    /// Multipart body of `addRequestComment`, same as Portal's comment form
+    /// sends
+    pub(crate) fn body(&self, internal_order_id: u32) -> RequestBody {
+        let mut fields = vec![
+            ("action", MultipartValue::Text("addRequestComment".to_string())),
+            ("id", MultipartValue::Text(internal_order_id.to_string())),
+            ("comment", MultipartValue::Text(self.text.clone())),
+        ];
+
+        if let Some(attachment) = &self.attachment {
+            fields.push(("commentfile", MultipartValue::File(attachment.clone())));
+        }
+
+        RequestBody::Multipart(fields)
+    }
+
+    /// Whether comment from view request has the text of this one.
+    /// [`FullComment`] keeps only the first line of the text and Portal may
+    /// collapse whitespaces, so texts are compared loosely
+    pub fn matches(&self, comment: &FullComment) -> bool {
+        let normalize = |text: &str| text.split_whitespace().join(" ");
+
+        let Some(text) = comment.text.as_deref().map(normalize) else {
+            return false;
+        };
+
+        !text.is_empty() && normalize(&self.text).starts_with(&text)
+    }
+
+    /// Finds this comment among comments of view request fetched `after` it
+    /// was sent. Comments that were already there `before` are skipped, so
+    /// earlier comment with the same text isn't taken for it
+    pub(crate) fn find_new(&self, before: Vec<FullComment>, after: Vec<FullComment>) -> Option<FullComment> {
+        let mut before = before;
+
+        after
+            .into_iter()
+            .filter(|comment| match before.iter().position(|old| old == comment) {
+                Some(position) => {
+                    before.swap_remove(position);
+                    false
+                },
+                None => true,
+            })
+            .filter(|comment| self.matches(comment))
+            .max_by_key(|comment| comment.datetime)
+    }
+}
@@ -0,0 +1,44 @@
+//! On-demand info parser
+//!
+//! View request page asks Portal to update customer's data from Kyivstar's
+//! system with `action=ondemandInfoRequest`. Portal responds with `1` if the
+//! data has been updated, so the order should be fetched again
+//!
+//! ## Example usage
+//!
+//! ```
+//! use nsg::on_demand_info::OnDemandInfo;
+//!
+//! let on_demand_info = OnDemandInfo::from("1");
+//!
+//! assert!(on_demand_info.updated);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct OnDemandInfo {
+    /// Customer's data has changed, order has to be fetched again to see it
+    pub updated: bool,
+    /// Anything Portal responded with except for `1` and `0`, i.e. an error
+    pub message: Option<String>,
+}
+
+impl OnDemandInfo {
+    /// Parse on-demand info from Portal's response
+    pub fn from(text: &str) -> OnDemandInfo {
+        log::debug!(target: "nsg", "Processing text: {:?}", text);
+
+        match text.trim() {
+            "1" => OnDemandInfo {
+                updated: true,
+                message: None,
+            },
+            "" | "0" => OnDemandInfo::default(),
+            message => OnDemandInfo {
+                updated: false,
+                message: Some(message.to_string()),
+            },
+        }
+    }
+}
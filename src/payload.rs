@@ -1,5 +1,11 @@
+use reqwest::header::{HeaderMap, CONTENT_TYPE};
+use reqwest::multipart::{Form, Part};
+use reqwest::RequestBuilder;
 use serde::Serialize;
 
+use crate::error::NsgResult;
+use crate::new_comment::Attachment;
+
 #[derive(Serialize)]
 #[doc(hidden)]
 pub struct LoginPayload<'a> {
@@ -18,3 +24,71 @@ pub struct Payload<'a> {
     pub city:   &'a str,
     pub data:   &'a str,
 }
+
+/// Body of POST request to Portal. Multipart body is kept as plain fields, so
+/// it can be sent again on retry or after relogin
+#[derive(Clone, Debug)]
+pub(crate) enum RequestBody {
+    UrlEncoded(String),
+    Multipart(Vec<(&'static str, MultipartValue)>),
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum MultipartValue {
+    Text(String),
+    File(Attachment),
+}
+
+impl From<String> for RequestBody {
+    fn from(payload: String) -> Self { RequestBody::UrlEncoded(payload) }
+}
+
+impl RequestBody {
+    /// Human readable body for logs. Files are described by name and size
+    /// instead of content
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            RequestBody::UrlEncoded(payload) => payload.clone(),
+            RequestBody::Multipart(fields) => fields
+                .iter()
+                .map(|(name, value)| match value {
+                    MultipartValue::Text(text) => format!("{name}={text:?}"),
+                    MultipartValue::File(attachment) => {
+                        format!("{name}=<{} ({} bytes)>", attachment.file_name, attachment.content.len())
+                    },
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    pub(crate) fn apply(&self, builder: RequestBuilder, mut headers: HeaderMap) -> NsgResult<RequestBuilder> {
+        match self {
+            RequestBody::UrlEncoded(payload) => Ok(builder.headers(headers).body(payload.clone())),
+            RequestBody::Multipart(fields) => {
+                // Boundary is set by reqwest
+                headers.remove(CONTENT_TYPE);
+
+                let mut form = Form::new();
+
+                for (name, value) in fields {
+                    form = match value {
+                        MultipartValue::Text(text) => form.text(*name, text.clone()),
+                        MultipartValue::File(attachment) => {
+                            let mut part =
+                                Part::bytes(attachment.content.clone()).file_name(attachment.file_name.clone());
+
+                            if let Some(mime) = &attachment.mime {
+                                part = part.mime_str(mime)?;
+                            }
+
+                            form.part(*name, part)
+                        },
+                    };
+                }
+
+                Ok(builder.headers(headers).multipart(form))
+            },
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Client-side rate limiting of requests to Portal
+//!
+//! ## Example usage
+//!
+//! ```
+//! use nsg::builder::NsgBuilder;
+//! use nsg::rate_limit::RateLimit;
+//! # use nsg::error::NsgError;
+//!
+//! # tokio_test::block_on(async {
+//! let nsg = NsgBuilder::new(
+//!     "https://net-stroy.itnet.lviv.ua".to_string(),
+//!     "client".to_string(),
+//!     "x.y".to_string(),
+//! )
+//! .rate_limit(RateLimit {
+//!     requests_per_second: 5,
+//!     max_in_flight:       2,
+//! })
+//! .build_with_creds("login".to_string(), "password_hash".to_string())
+//! .await?;
+//!
+//! # Ok::<(), NsgError>(())
+//! # });
+//! ```
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio::time::Instant;
+
+/// Limits applied to every request made with
+/// [`Nsg::request`](crate::Nsg::request)
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct RateLimit {
+    /// Requests are spread evenly, i.e. `5` means one request every 200ms.
+    /// `0` disables limiting by rate
+    pub requests_per_second: u32,
+    /// Maximum number of requests in flight at the same time, i.e. from
+    /// sending until the response body is read. Requests waiting before retry
+    /// aren't counted. `0` is treated as `1`
+    pub max_in_flight:       usize,
+}
+
+/// Shared state of [`RateLimit`]. Clones of [`Nsg`](crate::Nsg) share the
+/// same limiter
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    pub(crate) semaphore: Semaphore,
+    interval:             Option<Duration>,
+    next_slot:            Mutex<Instant>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(rate_limit: &RateLimit) -> RateLimiter {
+        RateLimiter {
+            semaphore: Semaphore::new(rate_limit.max_in_flight.max(1)),
+            interval:  (rate_limit.requests_per_second > 0)
+                .then(|| Duration::from_secs(1) / rate_limit.requests_per_second),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Waits until request is allowed to be sent. Request is considered in
+    /// flight until returned permit is dropped
+    pub(crate) async fn acquire(&self) -> SemaphorePermit<'_> {
+        let permit = self.semaphore.acquire().await.expect("Semaphore is never closed");
+
+        if let Some(interval) = self.interval {
+            let slot = {
+                let mut next_slot = self
+                    .next_slot
+                    .lock()
+                    .expect("Rate limiter lock should never be poisoned");
+                let slot = (*next_slot).max(Instant::now());
+                *next_slot = slot + interval;
+
+                slot
+            };
+
+            tokio::time::sleep_until(slot).await;
+        }
+
+        permit
+    }
+}
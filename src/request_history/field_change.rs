@@ -0,0 +1,106 @@
+//! Change of a single order field
+
+use itertools::Itertools;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+/// Single field changed by
+/// [`HistoryEntry`](super::history_entry::HistoryEntry). Portal shows changes
+/// as `new <= old`, so if field was set for the first
+/// time [`FieldChange::old_value`] is `None`
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// Field name without trailing colon, like "Статус" or "Дата перезвона"
+    pub field:     String,
+    pub new_value: Option<String>,
+    pub old_value: Option<String>,
+}
+
+impl FieldChange {
+    /// Parse change from row of two cells: field name and value. Value can be
+    /// nested table (like "Оборудование:"), in such case every nested row
+    /// becomes line of [`FieldChange::new_value`]
+    ///
+    /// ```
+    /// use nsg::request_history::field_change::FieldChange;
+    /// use scraper::{Html, Selector};
+    ///
+    /// let table = Html::parse_fragment(
+    ///     "<table><tr><td>Дата \
+    ///      перезвона:</td><td>2024-05-16&nbsp;<=&nbsp;2024-05-15</td></tr></table>",
+    /// );
+    /// let row = table
+    ///     .select(&Selector::parse("tr").unwrap())
+    ///     .next()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     FieldChange::from_row(row),
+    ///     Some(FieldChange {
+    ///         field:     "Дата перезвона".to_string(),
+    ///         new_value: Some("2024-05-16".to_string()),
+    ///         old_value: Some("2024-05-15".to_string()),
+    ///     })
+    /// );
+    /// ```
+    pub fn from_row(row: ElementRef<'_>) -> Option<FieldChange> {
+        let (name_cell, value_cell) = row.child_elements().collect_tuple()?;
+
+        let field = FieldChange::clean(&name_cell.text().collect::<String>())?
+            .trim_end_matches(':')
+            .to_string();
+
+        let nested_table_selector = Selector::parse("table").unwrap();
+        if let Some(nested_table) = value_cell.select(&nested_table_selector).next() {
+            let row_selector = Selector::parse("tr").unwrap();
+            let lines = nested_table
+                .select(&row_selector)
+                .map(|row| {
+                    row.child_elements()
+                        .map(|cell| cell.text().collect::<String>())
+                        .join(" ")
+                })
+                .join("\n");
+
+            return Some(FieldChange {
+                field,
+                new_value: FieldChange::clean(&lines),
+                old_value: None,
+            });
+        }
+
+        let value = value_cell.text().collect::<String>();
+        let (new_value, old_value) = match value.split_once("\u{a0}<=\u{a0}") {
+            Some((new_value, old_value)) => (FieldChange::clean(new_value), FieldChange::clean(old_value)),
+            None => (FieldChange::clean(&value), None),
+        };
+
+        Some(FieldChange {
+            field,
+            new_value,
+            old_value,
+        })
+    }
+
+    /// Parse all changes from table, for example one returned by
+    /// `action=viewRequestHistory`
+    pub fn from_table(html: &str) -> Vec<FieldChange> {
+        let fragment = Html::parse_fragment(html);
+        let row_selector = Selector::parse("tr").unwrap();
+
+        fragment
+            .select(&row_selector)
+            .filter_map(FieldChange::from_row)
+            .collect_vec()
+    }
+
+    fn clean(text: &str) -> Option<String> {
+        let text = text.trim_matches(|c: char| c.is_whitespace());
+
+        if text.is_empty() {
+            return None;
+        }
+
+        Some(text.to_string())
+    }
+}
@@ -0,0 +1,46 @@
+//! Order history entry
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use super::field_change::FieldChange;
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+/// Parsed history entry: who changed the order, when and which fields. For
+/// detailed information about field refer to it's documentation. Note that all
+/// fields will not fail hard allowing to work with partially valid entries
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct HistoryEntry {
+    /// Id of history entry, can be used with
+    /// [`Nsg::request_history_entry`](crate::Nsg::request_history_entry)
+    pub id:       Option<Result<u32, SerializableIntErrorKind>>,
+    /// User who made the change, "Система" for changes made by Portal itself
+    pub user:     Option<String>,
+    pub datetime: Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// Changed fields. Internal status is always present as "Статус"
+    pub changes:  Vec<FieldChange>,
+}
+
+/// Hence [`HistoryEntry`] will not fail hard, it's not necessary valid. You
+/// can guarantee validness of history entry with
+/// [`HistoryEntry::into_guaranteed`]. For detailed information about field
+/// refer to it's documentation or [`HistoryEntry`]'s documentation
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct GuaranteedHistoryEntry {
+    pub id:       u32,
+    pub user:     String,
+    pub datetime: DateTime<FixedOffset>,
+    pub changes:  Vec<FieldChange>,
+}
+
+impl HistoryEntry {
+    pub fn into_guaranteed(self) -> Option<GuaranteedHistoryEntry> {
+        Some(GuaranteedHistoryEntry {
+            id:       self.id?.ok()?,
+            user:     self.user?,
+            datetime: self.datetime?.ok()?,
+            changes:  self.changes,
+        })
+    }
+}
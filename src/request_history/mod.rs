@@ -0,0 +1,78 @@
+//! Order history parser (collection of [`HistoryEntry`]s)
+//!
+//! History is a part of view request page: every comment row is followed by
+//! hidden row with fields changed along with it
+//!
+//! ## Example usage
+//! You can find example HTMLs in `src/tests/assets/view_request/valid`
+//!
+//! ```
+//! use nsg::request_history::OrderHistory;
+//!
+//! let html = include_str!("../tests/assets/view_request/valid/1.html");
+//! let order_history = OrderHistory::from(&html);
+//!
+//! println!("History entries: {:#?}", order_history.0);
+//! ```
+
+pub mod field_change;
+pub mod history_entry;
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use self::field_change::FieldChange;
+use self::history_entry::HistoryEntry;
+use crate::data::full_comment::FullComment;
+
+/// Parsed order history containing vector of [`HistoryEntry`]s, newest first
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Default, Deserialize)]
+pub struct OrderHistory(pub Vec<HistoryEntry>);
+
+impl OrderHistory {
+    /// Parse order history from view request HTML
+    pub fn from(html: &str) -> OrderHistory {
+        let fragment = Html::parse_fragment(html);
+        // Trade report isn't a change of the order, see ViewRequest::set_comments
+        let selector = Selector::parse("#tblHistory tr[id^=\"row\"]:not(#rowtreport)").unwrap();
+
+        OrderHistory(fragment.select(&selector).map(OrderHistory::parse_entry).collect_vec())
+    }
+
+    fn parse_entry(row: ElementRef<'_>) -> HistoryEntry {
+        let mut entry = HistoryEntry {
+            id: row
+                .attr("id")
+                .map(|id| u32::from_str(id.trim_start_matches("row")).map_err(|err| (*err.kind()).into())),
+            ..Default::default()
+        };
+
+        let changes_selector = Selector::parse("td > table").unwrap();
+        let Some(changes_table) = row.select(&changes_selector).next() else {
+            return entry;
+        };
+
+        // Direct rows only, nested tables are handled by FieldChange itself
+        let rows = changes_table
+            .child_elements()
+            .flat_map(|tbody| tbody.child_elements())
+            .filter(|element| element.value().name() == "tr");
+
+        for change in rows.filter_map(FieldChange::from_row) {
+            if change.field != "Обновлено" {
+                entry.changes.push(change);
+                continue;
+            }
+
+            if let Some((user, datetime)) = change.new_value.as_deref().and_then(|value| value.rsplit_once(" в ")) {
+                entry.user = Some(user.trim().to_string());
+                entry.datetime = Some(FullComment::as_datetime(datetime.trim()));
+            }
+        }
+
+        entry
+    }
+}
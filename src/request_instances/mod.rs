@@ -0,0 +1,73 @@
+//! Request instances parser (collection of [`RequestInstance`]s)
+//!
+//! Orders reopened by Kyivstar have several instances which view request page
+//! loads with `request_getRequestInstances` JSON table query. Portal responds
+//! with JSON array of instances. Only the keys `instance`, `order_id`,
+//! `status`, `created` and `closed` are read
+//!
+//! **Experimental**: the example JSONs aren't captured from Portal, so the
+//! keys may not match the real response
+//!
+//! ## Example usage
+//! You can find example JSONs in `src/tests/assets/request_instances/valid`
+//!
+//! ```
+//! use nsg::request_instances::RequestInstances;
+//!
+//! let json = include_str!("../tests/assets/request_instances/valid/1.json");
+//! let request_instances = RequestInstances::from(&json);
+//!
+//! println!("Request instances: {:#?}", request_instances.0);
+//! ```
+
+pub mod request_instance;
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use self::request_instance::RequestInstance;
+use crate::data::full_comment::FullComment;
+use crate::data::status::Status;
+use crate::traits::json_value::JsonValue;
+
+/// Parsed request instances containing vector of [`RequestInstance`]s
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Default, Deserialize)]
+pub struct RequestInstances(pub Vec<RequestInstance>);
+
+impl RequestInstances {
+    /// Parse request instances from `request_getRequestInstances` JSON
+    pub fn from(json: &str) -> RequestInstances {
+        log::debug!(target: "nsg", "Processing JSON: {:?}", json);
+
+        let instances = match serde_json::from_str::<Vec<Value>>(json) {
+            Ok(instances) => instances,
+            Err(err) => {
+                log::warn!(target: "nsg", "Request instances are not a valid JSON array: {:?}", err);
+                return RequestInstances::default();
+            },
+        };
+
+        RequestInstances(
+            instances
+                .iter()
+                .map(|instance| RequestInstance {
+                    instance: instance
+                        .get_string("instance")
+                        .map(|instance| u32::from_str(&instance).map_err(|err| (*err.kind()).into())),
+                    order_id: instance
+                        .get_string("order_id")
+                        .map(|order_id| u32::from_str(&order_id).map_err(|err| (*err.kind()).into())),
+                    status:   instance.get_string("status").map(|status| Status::from_str(&status)),
+                    created:  instance
+                        .get_string("created")
+                        .map(|created| FullComment::as_datetime(&created)),
+                    closed:   instance
+                        .get_string("closed")
+                        .map(|closed| FullComment::as_datetime(&closed)),
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,51 @@
+//! Instance of the order
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::data::status::{Status, StatusError};
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+/// Parsed instance of the order. For detailed information about field refer
+/// to it's documentation. Note that all fields will not fail hard allowing to
+/// work with partially valid instances
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct RequestInstance {
+    /// Id of the instance
+    pub instance: Option<Result<u32, SerializableIntErrorKind>>,
+    /// Kyivstar's order id of the instance
+    pub order_id: Option<Result<u32, SerializableIntErrorKind>>,
+    pub status:   Option<Result<Status, StatusError>>,
+    pub created:  Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// `None` while instance is open
+    pub closed:   Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+}
+
+/// Hence [`RequestInstance`] will not fail hard, it's not necessary valid. You
+/// can guarantee validness of instance with
+/// [`RequestInstance::into_guaranteed`]. For detailed information about field
+/// refer to it's documentation or [`RequestInstance`]'s documentation
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct GuaranteedRequestInstance {
+    pub instance: u32,
+    pub order_id: u32,
+    pub status:   Status,
+    pub created:  DateTime<FixedOffset>,
+    pub closed:   Option<DateTime<FixedOffset>>,
+}
+
+impl RequestInstance {
+    pub fn into_guaranteed(self) -> Option<GuaranteedRequestInstance> {
+        Some(GuaranteedRequestInstance {
+            instance: self.instance?.ok()?,
+            order_id: self.order_id?.ok()?,
+            status:   self.status?.ok()?,
+            created:  self.created?.ok()?,
+            closed:   match self.closed {
+                Some(closed) => Some(closed.ok()?),
+                None => None,
+            },
+        })
+    }
+}
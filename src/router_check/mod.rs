@@ -0,0 +1,71 @@
+//! Router check parser
+//!
+//! View request page asks Kyivstar's system (SBMS) whether client's routers
+//! are activated with `action=checkSBMSRouter`. Portal responds with JSON
+//! where `1` is the regular router and `2` is the router PRO. Value is `0` if
+//! router isn't activated, otherwise it's the activation message
+//!
+//! ## Example usage
+//! You can find example JSONs in `src/tests/assets/router_check/valid`
+//!
+//! ```
+//! use nsg::router_check::RouterCheck;
+//!
+//! let json = include_str!("../tests/assets/router_check/valid/1.json");
+//! let router_check = RouterCheck::from(&json);
+//!
+//! println!("Online: {}", router_check.is_online());
+//! ```
+
+pub mod router_activation;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use self::router_activation::RouterActivation;
+
+/// Parsed result of the router check. Both routers are `None` if response
+/// isn't a valid JSON
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct RouterCheck {
+    /// "Роутер"
+    pub router:     Option<RouterActivation>,
+    /// "Роутер PRO"
+    pub router_pro: Option<RouterActivation>,
+}
+
+impl RouterCheck {
+    /// Parse router check from JSON
+    pub fn from(json: &str) -> RouterCheck {
+        log::debug!(target: "nsg", "Processing JSON: {:?}", json);
+
+        let value = match serde_json::from_str::<Value>(json) {
+            Ok(value) => value,
+            Err(err) => {
+                log::warn!(target: "nsg", "Router check is not a valid JSON: {:?}", err);
+                return RouterCheck::default();
+            },
+        };
+
+        RouterCheck {
+            router:     RouterCheck::get(&value, 1).map(RouterActivation::from),
+            router_pro: RouterCheck::get(&value, 2).map(RouterActivation::from),
+        }
+    }
+
+    /// Whether any of the routers is activated
+    pub fn is_online(&self) -> bool {
+        [&self.router, &self.router_pro]
+            .iter()
+            .any(|activation| activation.as_ref().is_some_and(|activation| activation.activated))
+    }
+
+    /// `d[1]` in Portal's JS works for both arrays and objects
+    fn get(value: &Value, index: usize) -> Option<&Value> {
+        match value {
+            Value::Array(array) => array.get(index),
+            Value::Object(object) => object.get(&index.to_string()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Activation status of a single router
+
+use chrono::{DateTime, FixedOffset};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::data::full_comment::FullComment;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+/// Activation status of a router. Everything except `activated` is extracted
+/// from the activation message and is `None` if message doesn't contain it
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct RouterActivation {
+    pub activated: bool,
+    /// MAC address or serial number of the router
+    pub device_id: Option<String>,
+    /// When router was seen by Kyivstar's system
+    pub last_seen: Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// Activation message as is, `None` if router isn't activated
+    pub message:   Option<String>,
+}
+
+impl RouterActivation {
+    /// `0` (number or string) means router isn't activated, anything else is
+    /// the activation message
+    pub fn from(value: &Value) -> RouterActivation {
+        let message = match value {
+            Value::String(string) if !string.is_empty() && string != "0" => string.trim().to_string(),
+            Value::Number(number) if number.as_u64() != Some(0) => number.to_string(),
+            _ => return RouterActivation::default(),
+        };
+
+        RouterActivation {
+            activated: true,
+            device_id: RouterActivation::get_device_id(&message),
+            last_seen: RouterActivation::get_last_seen(&message),
+            message:   Some(message),
+        }
+    }
+
+    fn get_device_id(message: &str) -> Option<String> {
+        let mac_regex = Regex::new(r"(?i)\b(?:[0-9a-f]{2}[:-]){5}[0-9a-f]{2}\b").unwrap();
+        let serial_regex = Regex::new(r"(?i)(?:s/n|sn|serial)\s*[:=]?\s*(?<serial>[0-9a-z]+)").unwrap();
+
+        if let Some(mac) = mac_regex.find(message) {
+            return Some(mac.as_str().to_uppercase());
+        }
+
+        Some(serial_regex.captures(message)?["serial"].to_string())
+    }
+
+    fn get_last_seen(message: &str) -> Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>> {
+        let regex = Regex::new(r"\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}").unwrap();
+        let datetime = regex.find(message)?.as_str();
+
+        Some(FullComment::as_datetime(datetime))
+    }
+}
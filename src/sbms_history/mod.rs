@@ -0,0 +1,66 @@
+//! SBMS history parser (collection of [`SbmsEntry`]s)
+//!
+//! View request page loads Kyivstar's (SBMS) change log of the order with
+//! `rq_sbmslog` table query. Columns are looked up by their headers: "Дата"
+//! (import datetime), "Статус у заказчика", "Комментарий", "Внимание"
+//! (attention flag) and "Подтвердил" (full name of user who confirmed the
+//! change)
+//!
+//! ## Example usage
+//! You can find example HTMLs in `src/tests/assets/sbms_history/valid`
+//!
+//! ```
+//! use nsg::sbms_history::SbmsHistory;
+//!
+//! let html = include_str!("../tests/assets/sbms_history/valid/1.html");
+//! let sbms_history = SbmsHistory::from(&html);
+//!
+//! println!("SBMS history: {:#?}", sbms_history.0);
+//! ```
+
+pub mod sbms_entry;
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use self::sbms_entry::SbmsEntry;
+use crate::data::full_comment::FullComment;
+use crate::table_query::table_row::TableRow;
+use crate::table_query::Table;
+
+/// Parsed SBMS history containing vector of [`SbmsEntry`]s
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Default, Deserialize)]
+pub struct SbmsHistory(pub Vec<SbmsEntry>);
+
+impl SbmsHistory {
+    /// Parse SBMS history from `rq_sbmslog` table query HTML
+    pub fn from(html: &str) -> SbmsHistory {
+        SbmsHistory(Table::from(html).rows.iter().map(SbmsHistory::parse_row).collect_vec())
+    }
+
+    /// Entries which still wait for confirmation, see
+    /// [`SbmsEntry::needs_confirmation`]
+    pub fn unconfirmed(&self) -> Vec<&SbmsEntry> {
+        self.0.iter().filter(|entry| entry.needs_confirmation()).collect_vec()
+    }
+
+    fn parse_row(row: &TableRow) -> SbmsEntry {
+        let text = |column| row.get_non_empty(column).map(str::to_string);
+
+        SbmsEntry {
+            id:           row
+                .id
+                .as_deref()
+                .map(|rowid| u32::from_str(rowid).map_err(|err| (*err.kind()).into())),
+            datetime:     row.get_non_empty("Дата").map(FullComment::as_datetime),
+            status:       text("Статус у заказчика"),
+            comment:      text("Комментарий"),
+            attention:    row
+                .get_non_empty("Внимание")
+                .map(|attention| u8::from_str(attention).map_err(|err| (*err.kind()).into())),
+            confirmed_by: text("Подтвердил"),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Entry of SBMS history
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+/// Parsed change of the order imported from Kyivstar's system (SBMS). For
+/// detailed information about field refer to it's documentation. Note that
+/// all fields will not fail hard allowing to work with partially valid entries
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct SbmsEntry {
+    /// Id of entry, the one used in `?action=confirmSBMSChange`
+    pub id:           Option<Result<u32, SerializableIntErrorKind>>,
+    /// When change was imported
+    pub datetime:     Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// Status of the order at Kyivstar's side, like `Отказ`
+    pub status:       Option<String>,
+    pub comment:      Option<String>,
+    /// `0` if change doesn't need confirmation, `1` if it's urgent and any
+    /// other value if it should be confirmed
+    pub attention:    Option<Result<u8, SerializableIntErrorKind>>,
+    /// Full name of user who confirmed the change
+    pub confirmed_by: Option<String>,
+}
+
+/// Hence [`SbmsEntry`] will not fail hard, it's not necessary valid. You can
+/// guarantee validness of entry with [`SbmsEntry::into_guaranteed`]. For
+/// detailed information about field refer to it's documentation or
+/// [`SbmsEntry`]'s documentation
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct GuaranteedSbmsEntry {
+    pub id:           u32,
+    pub datetime:     DateTime<FixedOffset>,
+    pub status:       String,
+    pub comment:      Option<String>,
+    pub attention:    u8,
+    pub confirmed_by: Option<String>,
+}
+
+impl SbmsEntry {
+    pub fn into_guaranteed(self) -> Option<GuaranteedSbmsEntry> {
+        Some(GuaranteedSbmsEntry {
+            id:           self.id?.ok()?,
+            datetime:     self.datetime?.ok()?,
+            status:       self.status?,
+            comment:      self.comment,
+            attention:    self.attention?.ok()?,
+            confirmed_by: self.confirmed_by,
+        })
+    }
+
+    /// Change requires attention and nobody has confirmed it yet
+    pub fn needs_confirmation(&self) -> bool {
+        matches!(self.attention, Some(Ok(attention)) if attention != 0) && self.confirmed_by.is_none()
+    }
+}
@@ -0,0 +1,105 @@
+//! Service hint parser
+//!
+//! View request page shows a tooltip with service details when hovering a row
+//! of the services table. The tooltip is fetched with `action=getServiceHint`
+//! and is a piece of HTML with `Label: value` lines
+//!
+//! ## Example usage
+//! You can find example HTMLs in `src/tests/assets/service_hint/valid`
+//!
+//! ```
+//! use nsg::service_hint::ServiceHint;
+//!
+//! let html = include_str!("../tests/assets/service_hint/valid/1.html");
+//! let service_hint = ServiceHint::from(&html);
+//!
+//! println!("Bring a tuner: {}", service_hint.needs_tuner());
+//! ```
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+
+/// Parsed service hint. Lines which aren't recognized are kept in
+/// [`ServiceHint::lines`] anyway
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct ServiceHint {
+    /// First line without a label, usually the package name
+    pub name:        Option<String>,
+    /// As is, i.e. `1 Гбит/с`
+    pub speed:       Option<String>,
+    pub tv_channels: Option<Result<u32, SerializableIntErrorKind>>,
+    /// Equipment required for the service
+    pub equipment:   Vec<String>,
+    /// Every non-empty line of the hint
+    pub lines:       Vec<String>,
+}
+
+impl ServiceHint {
+    /// Parse service hint from HTML
+    pub fn from(html: &str) -> ServiceHint {
+        log::debug!(target: "nsg", "Processing HTML: {:?}", html);
+        let mut service_hint = ServiceHint::default();
+
+        let html_fragment = Html::parse_fragment(html);
+        service_hint.lines = html_fragment
+            .root_element()
+            .text()
+            .flat_map(|text| text.lines())
+            .map(|line| line.split_whitespace().join(" "))
+            .filter(|line| !line.is_empty())
+            .collect_vec();
+
+        for line in &service_hint.lines {
+            let Some((label, value)) = line.split_once(':') else {
+                if service_hint.name.is_none() {
+                    service_hint.name = Some(line.clone());
+                }
+                continue;
+            };
+            let label = label.trim().to_lowercase();
+            let value = value.trim();
+
+            if ["скорость", "швидкість"].iter().any(|name| label.contains(name)) {
+                service_hint.speed = Some(value.to_string());
+            } else if ["канал"].iter().any(|name| label.contains(name)) {
+                service_hint.tv_channels = Some(
+                    u32::from_str(
+                        &value
+                            .chars()
+                            .take_while(|char| char.is_ascii_digit())
+                            .collect::<String>(),
+                    )
+                    .map_err(|err| (*err.kind()).into()),
+                );
+            } else if ["оборудование", "обладнання"].iter().any(|name| label.contains(name)) {
+                service_hint.equipment.extend(
+                    value
+                        .split([',', ';'])
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(str::to_string),
+                );
+            } else {
+                log::debug!(target: "nsg", "Unknown service hint line: {:?}", line);
+            }
+        }
+
+        service_hint
+    }
+
+    /// Whether installer should bring a TV tuner (set-top box) for the service
+    pub fn needs_tuner(&self) -> bool {
+        self.equipment.iter().any(|item| {
+            let item = item.to_lowercase();
+
+            ["тюнер", "tuner", "приставк", "stb"]
+                .iter()
+                .any(|name| item.contains(name))
+        })
+    }
+}
@@ -0,0 +1,139 @@
+//! Staircases parser (collection of [`Staircase`]s and [`StaircaseComment`]s)
+//!
+//! View request page loads staircases of the building with `rq_staircases`
+//! table query and notes about them with `rq_staircasecomments` table query.
+//! Both are requested by Portal's address id (`addrid`), not by the order.
+//! Staircase columns are: number, ЛКД, ports, ВБСС, ТКД, first and last
+//! apartment numbers. Comment columns are: staircase number, text, author and
+//! creation datetime
+//!
+//! ## Example usage
+//! You can find example HTMLs in `src/tests/assets/staircases/valid`
+//!
+//! ```
+//! use nsg::staircases::Staircases;
+//!
+//! let staircases_html = include_str!("../tests/assets/staircases/valid/staircases.html");
+//! let comments_html = include_str!("../tests/assets/staircases/valid/comments.html");
+//! let staircases = Staircases::from(13879, &staircases_html, &comments_html);
+//!
+//! println!("Staircases: {:#?}", staircases);
+//! ```
+
+pub mod staircase;
+pub mod staircase_comment;
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use self::staircase::Staircase;
+use self::staircase_comment::StaircaseComment;
+use crate::data::address::Address;
+use crate::data::full_comment::FullComment;
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+
+/// Staircases of the building and notes about them, like door codes, keys or
+/// where the switch is
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct Staircases {
+    /// Portal's id of the address (`addrid`)
+    pub address_id: u32,
+    /// `None` unless staircases are fetched for the order, see
+    /// [`Nsg::staircases`](crate::Nsg::staircases)
+    pub address:    Option<Address>,
+    pub staircases: Vec<Staircase>,
+    pub comments:   Vec<StaircaseComment>,
+}
+
+impl Staircases {
+    /// Parse staircases from `rq_staircases` and `rq_staircasecomments` table
+    /// queries HTML
+    pub fn from(address_id: u32, staircases_html: &str, comments_html: &str) -> Staircases {
+        Staircases {
+            address_id,
+            address: None,
+            staircases: Staircases::parse_staircases(staircases_html),
+            comments: Staircases::parse_comments(comments_html),
+        }
+    }
+
+    /// Comments related to the staircase with given number. Comments without
+    /// staircase number relate to the whole building and are included too
+    pub fn comments_for(&self, number: u32) -> Vec<&StaircaseComment> {
+        self.comments
+            .iter()
+            .filter(|comment| {
+                matches!(comment.staircase, Some(Ok(staircase)) if staircase == number) || comment.staircase.is_none()
+            })
+            .collect_vec()
+    }
+
+    fn parse_staircases(html: &str) -> Vec<Staircase> {
+        let fragment = Html::parse_fragment(&format!("<table>{html}</table>"));
+        let selector = Selector::parse("tr.tablerrow").unwrap();
+
+        fragment
+            .select(&selector)
+            .map(|row| {
+                let mut children = row.child_elements();
+
+                Staircase {
+                    id:              Staircases::get_id(&row),
+                    number:          Staircases::get_prop(children.next(), Staircases::as_u32),
+                    lkd:             Staircases::get_text(children.next()),
+                    ports:           Staircases::get_text(children.next()),
+                    vbss:            Staircases::get_text(children.next()),
+                    tkd:             Staircases::get_text(children.next()),
+                    apartments_from: Staircases::get_prop(children.next(), Staircases::as_u32),
+                    apartments_to:   Staircases::get_prop(children.next(), Staircases::as_u32),
+                }
+            })
+            .collect_vec()
+    }
+
+    fn parse_comments(html: &str) -> Vec<StaircaseComment> {
+        let fragment = Html::parse_fragment(&format!("<table>{html}</table>"));
+        let selector = Selector::parse("tr.tablerrow").unwrap();
+
+        fragment
+            .select(&selector)
+            .map(|row| {
+                let mut children = row.child_elements();
+
+                StaircaseComment {
+                    id:        Staircases::get_id(&row),
+                    staircase: Staircases::get_prop(children.next(), Staircases::as_u32),
+                    text:      Staircases::get_text(children.next()),
+                    author:    Staircases::get_text(children.next()),
+                    created:   Staircases::get_prop(children.next(), |value| Some(FullComment::as_datetime(&value))),
+                }
+            })
+            .collect_vec()
+    }
+
+    fn get_id(row: &ElementRef<'_>) -> Option<Result<u32, SerializableIntErrorKind>> {
+        Staircases::as_u32(row.attr("rowid")?.to_string())
+    }
+
+    fn get_text(element: Option<ElementRef<'_>>) -> Option<String> {
+        let string = element?.text().collect_vec().join(" ");
+        let string = string.trim();
+
+        if string.is_empty() {
+            return None;
+        }
+
+        Some(string.to_string())
+    }
+
+    fn get_prop<T>(element: Option<ElementRef<'_>>, callback: impl FnOnce(String) -> Option<T>) -> Option<T> {
+        callback(Staircases::get_text(element)?)
+    }
+
+    fn as_u32(value: String) -> Option<Result<u32, SerializableIntErrorKind>> {
+        Some(u32::from_str(&value).map_err(|err| (*err.kind()).into()))
+    }
+}
@@ -0,0 +1,36 @@
+//! Staircase (entrance) of the building
+
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+
+/// Parsed staircase of the building. All fields except numbers are free-form
+/// text filled by Portal's users and are `None` if empty
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct Staircase {
+    pub id:              Option<Result<u32, SerializableIntErrorKind>>,
+    /// Staircase number
+    pub number:          Option<Result<u32, SerializableIntErrorKind>>,
+    /// "ЛКД"
+    pub lkd:             Option<String>,
+    /// "Порты"
+    pub ports:           Option<String>,
+    /// "ВБСС"
+    pub vbss:            Option<String>,
+    /// "ТКД"
+    pub tkd:             Option<String>,
+    /// First apartment number in the staircase
+    pub apartments_from: Option<Result<u32, SerializableIntErrorKind>>,
+    /// Last apartment number in the staircase
+    pub apartments_to:   Option<Result<u32, SerializableIntErrorKind>>,
+}
+
+impl Staircase {
+    /// Whether apartment is located in this staircase
+    pub fn contains_apartment(&self, apartment: u32) -> bool {
+        match (&self.apartments_from, &self.apartments_to) {
+            (Some(Ok(from)), Some(Ok(to))) => (*from..=*to).contains(&apartment),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Comment about staircase
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+/// Parsed comment about staircase, like door code or where the switch is
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct StaircaseComment {
+    pub id:        Option<Result<u32, SerializableIntErrorKind>>,
+    /// Staircase number, `None` if comment relates to the whole building
+    pub staircase: Option<Result<u32, SerializableIntErrorKind>>,
+    pub text:      Option<String>,
+    /// Full name of user who left the comment
+    pub author:    Option<String>,
+    pub created:   Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+}
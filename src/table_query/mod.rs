@@ -0,0 +1,153 @@
+//! Generic table query parser
+//!
+//! Portal pages load many sub-tables with `TableQuery` and `JSONTableQuery`
+//! jQuery plugins by query name, like `rq_incidents` or `request_invops`.
+//! `TableQuery` responds with HTML rows, while `JSONTableQuery` responds with
+//! JSON array of objects. This module allows to reach tables which don't have
+//! dedicated data pillar yet, see [`Nsg::table_query`](crate::Nsg::table_query)
+//! and [`Nsg::json_table_query`](crate::Nsg::json_table_query)
+//!
+//! ## Example usage
+//! You can find example HTMLs in `src/tests/assets/incidents/valid`
+//!
+//! ```
+//! use nsg::table_query::Table;
+//!
+//! let html = include_str!("../tests/assets/incidents/valid/1.html");
+//! let table = Table::from(&html);
+//!
+//! for row in table.rows {
+//!     println!("{:?}: {:?}", row.id, row.get("Статус"));
+//! }
+//! ```
+
+pub mod table_row;
+
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+use scraper::{ElementRef, Html, Selector};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use self::table_row::TableRow;
+use crate::error::{NsgError, NsgResult};
+
+/// Parsed `TableQuery` response
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct Table {
+    /// Column names from the header row, empty if table has no header
+    pub headers: Vec<String>,
+    pub rows:    Vec<TableRow>,
+}
+
+impl Table {
+    /// Parse table from `TableQuery` HTML. Column name of the cell is its
+    /// header text, or if there's no header for it, its `class` attribute
+    /// (Portal uses column names as classes, like `td.updaterfio`), or if
+    /// there's no class, its zero-based index
+    pub fn from(html: &str) -> Table {
+        let fragment = Html::parse_fragment(&format!("<table>{html}</table>"));
+
+        let any_row_selector = Selector::parse("tr").unwrap();
+        let has_cells = |row: &ElementRef<'_>, name: &str| row.child_elements().any(|cell| cell.value().name() == name);
+
+        let headers = fragment
+            .select(&any_row_selector)
+            .find(|row| has_cells(row, "th"))
+            .map(|header_row| {
+                header_row
+                    .child_elements()
+                    .map(|header| header.text().collect::<String>().trim().to_string())
+                    .collect_vec()
+            })
+            .unwrap_or_default();
+
+        let row_selector = Selector::parse("tr.tablerrow").unwrap();
+        let mut row_elements = fragment.select(&row_selector).collect_vec();
+
+        if row_elements.is_empty() {
+            row_elements = fragment
+                .select(&any_row_selector)
+                .filter(|row| has_cells(row, "td"))
+                .collect_vec();
+        }
+
+        let rows = row_elements
+            .into_iter()
+            .map(|row| Table::parse_row(row, &headers))
+            .collect_vec();
+
+        Table { headers, rows }
+    }
+
+    fn parse_row(row: ElementRef<'_>, headers: &[String]) -> TableRow {
+        let mut cells = BTreeMap::new();
+
+        for (index, cell) in row
+            .child_elements()
+            .filter(|cell| cell.value().name() == "td")
+            .enumerate()
+        {
+            let column = headers
+                .get(index)
+                .filter(|header| !header.is_empty())
+                .cloned()
+                .or_else(|| cell.attr("class").map(|class| class.trim().to_string()))
+                .filter(|column| !column.is_empty() && !cells.contains_key(column))
+                .unwrap_or_else(|| index.to_string());
+
+            cells.insert(column, cell.text().collect_vec().join(" ").trim().to_string());
+        }
+
+        TableRow {
+            id: row.attr("rowid").map(str::to_string),
+            cells,
+        }
+    }
+
+    /// Deserializes rows into caller's struct. All cells are strings, so
+    /// fields should be `String` or `Option<String>`, use
+    /// `#[serde(rename = "...")]` to map column names
+    ///
+    /// ```
+    /// use nsg::table_query::Table;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Incident {
+    ///     #[serde(rename = "№")]
+    ///     id:     String,
+    ///     #[serde(rename = "Статус")]
+    ///     status: String,
+    /// }
+    ///
+    /// let html = include_str!("../tests/assets/incidents/valid/1.html");
+    /// let incidents = Table::from(&html).deserialize_rows::<Incident>().unwrap();
+    ///
+    /// assert_eq!(incidents[0].id, "31874");
+    /// assert_eq!(incidents[0].status, "Закрыт");
+    /// ```
+    pub fn deserialize_rows<T: DeserializeOwned>(&self) -> NsgResult<Vec<T>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let object = row
+                    .cells
+                    .iter()
+                    .map(|(column, cell)| (column.clone(), Value::String(cell.clone())))
+                    .collect();
+
+                serde_json::from_value(Value::Object(object)).map_err(|err| NsgError::DeserializeError(err.to_string()))
+            })
+            .collect()
+    }
+
+    /// Deserializes `JSONTableQuery` response (JSON array of objects) into
+    /// caller's struct. Use `BTreeMap<String, serde_json::Value>` to get
+    /// untyped rows
+    pub fn deserialize_json<T: DeserializeOwned>(json: &str) -> NsgResult<Vec<T>> {
+        serde_json::from_str(json).map_err(|err| NsgError::DeserializeError(err.to_string()))
+    }
+}
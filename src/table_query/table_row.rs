@@ -0,0 +1,22 @@
+//! Row of [`Table`](super::Table)
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Row of table query with cells keyed by column name
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct TableRow {
+    /// Value of `rowid` attribute, usually id of the entity in the row
+    pub id:    Option<String>,
+    /// Column name to cell text. See [`Table::from`](super::Table::from) for
+    /// how column names are resolved
+    pub cells: BTreeMap<String, String>,
+}
+
+impl TableRow {
+    pub fn get(&self, column: &str) -> Option<&str> { self.cells.get(column).map(String::as_str) }
+
+    /// Same as [`TableRow::get`], but empty cell is `None` too
+    pub fn get_non_empty(&self, column: &str) -> Option<&str> { self.get(column).filter(|cell| !cell.is_empty()) }
+}
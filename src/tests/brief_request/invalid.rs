@@ -16,6 +16,17 @@ test! {
     // address: { None, invalid_address; },
 }
 
+#[test]
+fn callback_date_invalid_naive_date() {
+    let html = fs::read_to_string("src/tests/assets/brief_request/invalid/callback_date.html")
+        .expect("Should have been able to read the file");
+
+    assert_eq!(
+        BriefRequest::from(&html).deadlines.callback_date,
+        Some(Err(SerializableParseErrorKind::OutOfRange))
+    );
+}
+
 #[test]
 fn should_not_guarantee() {
     let html = fs::read_to_string("src/tests/assets/brief_request/valid/1.html")
@@ -1,12 +1,12 @@
 use std::fs;
 
-use chrono::{DateTime, NaiveTime};
+use chrono::{DateTime, NaiveDate, NaiveTime};
 
 use crate::brief_request::BriefRequest;
 use crate::data::address::Address;
 use crate::data::comment::Comment;
 use crate::data::internal_status::InternalStatus;
-use crate::data::order_type::OrderType;
+use crate::data::order_type::{OrderSubtype, OrderType};
 use crate::data::status::Status;
 use crate::data::time_constrains::TimeConstrains;
 
@@ -29,7 +29,13 @@ fn fields() {
 
     assert_eq!(brief_request.order_id, Some(Ok(13356689)));
     assert_eq!(brief_request.internal_order_id, Some(Ok(950974)));
-    assert_eq!(brief_request.order_type, Some(Ok(OrderType::NetGigabit)));
+    assert_eq!(
+        brief_request.order_type,
+        Some(Ok(OrderType {
+            category: None,
+            subtype:  OrderSubtype::Gigabit,
+        }))
+    );
     assert_eq!(
         brief_request.creation_date,
         Some(Ok(DateTime::parse_from_rfc3339("2024-05-16 15:10:23+03:00").unwrap()))
@@ -80,3 +86,39 @@ fn fields() {
         "+38067███████".to_string()
     ]);
 }
+
+#[test]
+fn deadlines() {
+    let html = fs::read_to_string("src/tests/assets/brief_request/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let brief_request = BriefRequest::from(&html);
+
+    assert!(!brief_request.deadlines.urgent);
+    assert_eq!(
+        brief_request.deadlines.deadline,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-18 15:10:23+03:00").unwrap()))
+    );
+    assert_eq!(brief_request.deadlines.planned_activation, None);
+    assert_eq!(brief_request.deadlines.desired_time, None);
+    assert_eq!(
+        brief_request.scheduled_at,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-17 11:30:00+03:00").unwrap()))
+    );
+    assert_eq!(
+        brief_request.deadlines.callback_date,
+        Some(Ok(NaiveDate::from_ymd_opt(2024, 5, 17).unwrap()))
+    );
+}
+
+#[test]
+fn urgent() {
+    let html = fs::read_to_string("src/tests/assets/brief_request/valid/1.html")
+        .expect("Should have been able to read the file")
+        .replace(
+            "<th align=\"left\">Дата перезвона:</th>",
+            "<th align=\"left\">Срочная:</th><td>Да</td></tr><tr><th align=\"left\">Дата перезвона:</th>",
+        );
+
+    assert!(BriefRequest::from(&html).deadlines.urgent);
+}
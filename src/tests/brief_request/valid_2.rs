@@ -6,7 +6,7 @@ use crate::brief_request::BriefRequest;
 use crate::data::address::Address;
 use crate::data::comment::Comment;
 use crate::data::internal_status::InternalStatus;
-use crate::data::order_type::OrderType;
+use crate::data::order_type::{OrderSubtype, OrderType};
 use crate::data::status::Status;
 use crate::data::time_constrains::TimeConstrains;
 
@@ -29,7 +29,13 @@ fn fields() {
 
     assert_eq!(brief_request.order_id, Some(Ok(13373621)));
     assert_eq!(brief_request.internal_order_id, Some(Ok(953389)));
-    assert_eq!(brief_request.order_type, Some(Ok(OrderType::NetBroken)));
+    assert_eq!(
+        brief_request.order_type,
+        Some(Ok(OrderType {
+            category: None,
+            subtype:  OrderSubtype::ServiceComplaints,
+        }))
+    );
     assert_eq!(
         brief_request.creation_date,
         Some(Ok(DateTime::parse_from_rfc3339("2024-05-26 12:41:56+03:00").unwrap()))
@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use crate::builder::{NsgBuilder, RetryPolicy};
+use crate::error::NsgError;
+
+fn builder() -> NsgBuilder {
+    NsgBuilder::new(
+        "https://net-stroy.itnet.lviv.ua".to_string(),
+        "client".to_string(),
+        "x.y".to_string(),
+    )
+}
+
+#[test]
+fn build_with_session() {
+    let nsg = builder()
+        .connect_timeout(Duration::from_secs(5))
+        .timeout(Duration::from_secs(30))
+        .proxy("http://127.0.0.1:8080".to_string())
+        .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+        .unwrap();
+
+    assert_eq!(nsg.session(), Some("75tlg96e5id8c3r0k0d6c8j4s0".to_string()));
+    assert_eq!(
+        nsg.construct_headers()["cookie"],
+        "SESSION_nsg=75tlg96e5id8c3r0k0d6c8j4s0"
+    );
+}
+
+#[test]
+fn invalid_proxy() {
+    let nsg = builder()
+        .proxy("\"&$^".to_string())
+        .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string());
+
+    assert_eq!(nsg.err(), Some(NsgError::ReqwestBuilderError));
+}
+
+#[test]
+fn backoff() {
+    let policy = RetryPolicy::new(3, Duration::from_millis(500));
+
+    assert_eq!(policy.backoff(0), Duration::from_millis(500));
+    assert_eq!(policy.backoff(1), Duration::from_secs(1));
+    assert_eq!(policy.backoff(3), Duration::from_secs(4));
+    assert_eq!(policy.backoff(6), Duration::from_secs(30));
+    assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(30));
+}
+
+#[test]
+fn default_policy_does_not_retry() {
+    assert_eq!(RetryPolicy::default().max_retries, 0);
+}
+
+#[test]
+fn connect_error_is_retryable() {
+    // Bind to a free port and close it right away, so nothing listens there
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+
+    let err = tokio_test::block_on(reqwest::Client::new().get(format!("http://127.0.0.1:{port}")).send())
+        .map_err(NsgError::from)
+        .unwrap_err();
+
+    assert_eq!(err, NsgError::ReqwestConnectError);
+    assert!(err.is_retryable());
+    assert!(!NsgError::ReqwestBuilderError.is_retryable());
+    assert!(!NsgError::SessionExpired.is_retryable());
+}
+
+#[test]
+fn dry_run() {
+    let nsg = builder()
+        .dry_run(true)
+        .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+        .unwrap();
+
+    assert!(nsg.is_dry_run());
+    assert!(!builder()
+        .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+        .unwrap()
+        .is_dry_run());
+
+    tokio_test::block_on(async {
+        assert_eq!(nsg.rollback_request(950207, "Абонент передумал").await, Ok(()));
+        assert_eq!(nsg.confirm_sbms_change(482954).await, Ok(()));
+    });
+}
+
+#[test]
+fn relogin_without_creds() {
+    let nsg = builder()
+        .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+        .unwrap();
+
+    assert_eq!(tokio_test::block_on(nsg.relogin()), Err(NsgError::SessionExpired));
+}
+
+#[test]
+fn writes_are_not_retried() {
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let nsg = NsgBuilder::new(
+        format!("http://127.0.0.1:{port}"),
+        "client".to_string(),
+        "x.y".to_string(),
+    )
+    .retry_policy(RetryPolicy::new(3, Duration::from_secs(1)))
+    .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+    .unwrap();
+
+    tokio_test::block_on(async {
+        let start = std::time::Instant::now();
+
+        assert_eq!(
+            nsg.confirm_sbms_change(482954).await,
+            Err(NsgError::ReqwestConnectError)
+        );
+        assert!(start.elapsed() < Duration::from_secs(1));
+    });
+}
@@ -0,0 +1,100 @@
+use std::fs;
+
+use chrono::NaiveDate;
+
+use crate::brief_request::BriefRequest;
+use crate::data::cable_diagnostics::{CableDiagnostics, CablePair, PairState};
+use crate::view_request::ViewRequest;
+
+#[test]
+fn brief_request_first_comment() {
+    let html = fs::read_to_string("src/tests/assets/brief_request/valid/2.html")
+        .expect("Should have been able to read the file");
+
+    let diagnostics = BriefRequest::from(&html)
+        .first_comment
+        .unwrap()
+        .unwrap()
+        .diagnostics()
+        .unwrap();
+
+    assert_eq!(diagnostics.pairs, vec![
+        CablePair {
+            name:   'A',
+            length: Some(52),
+            state:  Some(PairState::Open),
+        },
+        CablePair {
+            name:   'B',
+            length: Some(56),
+            state:  Some(PairState::Open),
+        },
+    ]);
+    assert_eq!(
+        diagnostics.repair_date,
+        Some(Ok(NaiveDate::from_ymd_opt(2024, 5, 26).unwrap()))
+    );
+    assert_eq!(diagnostics.symptoms, vec![
+        "Індикатор \"WAN\" не горить",
+        "DOWN",
+        "Кабель відкритий на N більше 20 метрів"
+    ]);
+    assert_eq!(diagnostics.fault_distance(), Some(52));
+}
+
+#[test]
+fn view_request_comments() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/2.html")
+        .expect("Should have been able to read the file");
+
+    let diagnostics = ViewRequest::from(&html)
+        .comments
+        .iter()
+        .flatten()
+        .find_map(|full_comment| full_comment.diagnostics())
+        .unwrap();
+
+    assert_eq!(diagnostics.pairs.len(), 2);
+    assert_eq!(diagnostics.fault_distance(), Some(1));
+}
+
+#[test]
+fn repair_date_only() {
+    let diagnostics = CableDiagnostics::from(
+        "Заявка на замену кабеля;Замена кабеля с 2 на 4 пары для Гигабита;Repair date-2024-05-14;Указать метр \
+         повреждения: ; Для можливості замовлення ТП Гігабіт",
+    )
+    .unwrap();
+
+    assert_eq!(diagnostics.pairs, vec![]);
+    assert_eq!(
+        diagnostics.repair_date,
+        Some(Ok(NaiveDate::from_ymd_opt(2024, 5, 14).unwrap()))
+    );
+    assert_eq!(diagnostics.symptoms, vec![
+        "Заявка на замену кабеля",
+        "Замена кабеля с 2 на 4 пары для Гигабита",
+        "Для можливості замовлення ТП Гігабіт"
+    ]);
+    assert_eq!(diagnostics.fault_distance(), None);
+}
+
+#[test]
+fn fault_distance() {
+    let diagnostics = CableDiagnostics::from(
+        "Указать метр повреждения-Длина кабеля\tPair A length: 24meter(s) Pair B length: 1meter(s) Pair A state: Ok \
+         Pair B state: Short Pair C state: Crosstalk",
+    )
+    .unwrap();
+
+    assert_eq!(diagnostics.fault_distance(), Some(1));
+    assert_eq!(
+        diagnostics.pairs[2].state,
+        Some(PairState::Other("Crosstalk".to_string()))
+    );
+}
+
+#[test]
+fn no_diagnostics() {
+    assert_eq!(CableDiagnostics::from("Полностью заменил кабель"), None);
+}
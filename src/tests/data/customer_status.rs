@@ -0,0 +1,50 @@
+use chrono::DateTime;
+
+use crate::data::customer_status::{CustomerStatus, CustomerStatusError};
+use crate::data::internal_status::{InternalStatus, InternalStatusError};
+use crate::data::status::{Status, StatusError};
+
+#[test]
+fn with_user_and_datetime() {
+    assert_eq!(
+        CustomerStatus::from(
+            "Статус у заказчика:Отклонена. Переведено в Не активировано (████████ ██████ ███████████,2024-05-16 \
+             16:42:20)"
+        ),
+        Ok(CustomerStatus {
+            status:          Status::Declined,
+            internal_status: Some(InternalStatus::NotActivated),
+            user:            Some("████████ ██████ ███████████".to_string()),
+            datetime:        Some(DateTime::parse_from_rfc3339("2024-05-16 16:42:20+03:00").unwrap()),
+        })
+    );
+}
+
+#[test]
+fn without_internal_status() {
+    assert_eq!(
+        CustomerStatus::from("Статус у заказчика:Выполнена"),
+        Ok(CustomerStatus {
+            status:          Status::Completed,
+            internal_status: None,
+            user:            None,
+            datetime:        None,
+        })
+    );
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(
+        CustomerStatus::from("Статус у заказчика:Потеряна. Переведено в Не активировано"),
+        Err(CustomerStatusError::InvalidStatus(StatusError::InvalidStatus(
+            "Потеряна".to_string()
+        )))
+    );
+    assert_eq!(
+        CustomerStatus::from("Статус у заказчика:Отклонена. Переведено в Потеряно"),
+        Err(CustomerStatusError::InvalidInternalStatus(
+            InternalStatusError::InvalidStrStatus("Потеряно".to_string())
+        ))
+    );
+}
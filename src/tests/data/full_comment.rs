@@ -153,3 +153,15 @@ fn invalid_internal_status() {
         ))
     )
 }
+
+#[test]
+fn datetime_in_dst_gap() {
+    assert_eq!(
+        FullComment::as_datetime("2024-03-31 03:30:00"),
+        Err(SerializableParseErrorKind::Impossible)
+    );
+    assert_eq!(
+        FullComment::as_datetime("2024-03-31 04:30:00"),
+        Ok(DateTime::parse_from_rfc3339("2024-03-31T04:30:00+03:00").unwrap())
+    );
+}
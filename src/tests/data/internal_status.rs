@@ -27,3 +27,20 @@ fn from_str() {
         Err(InternalStatusError::InvalidStrStatus("???".to_string()))
     );
 }
+
+#[test]
+fn display() {
+    for status in [
+        InternalStatus::Assigned,
+        InternalStatus::Returned,
+        InternalStatus::Completed,
+        InternalStatus::Rejected,
+        InternalStatus::NotActivated,
+        InternalStatus::New,
+        InternalStatus::Agreed,
+        InternalStatus::NotCompleted,
+        InternalStatus::Delayed,
+    ] {
+        assert_eq!(InternalStatus::from_str(&status.to_string()), Ok(status));
+    }
+}
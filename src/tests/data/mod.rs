@@ -1,8 +1,12 @@
 mod address;
+mod cable_diagnostics;
 mod comment;
+mod customer_status;
 mod full_comment;
 mod internal_status;
 mod mdu;
 mod order_type;
+mod service;
 mod status;
+mod technology;
 mod time_constrains;
@@ -1,100 +1,171 @@
 use std::str::FromStr;
 
-use crate::data::order_type::{OrderType, OrderTypeError};
+use crate::data::order_type::{OrderCategory, OrderSubtype, OrderType, OrderTypeError};
+
+fn order_type(category: Option<OrderCategory>, subtype: OrderSubtype) -> Result<OrderType, OrderTypeError> {
+    Ok(OrderType { category, subtype })
+}
 
 #[test]
 fn from_str() {
     assert_eq!(
         OrderType::from_str("Сервис-Жалобы на обслуживание"),
-        Ok(OrderType::NetBroken)
+        order_type(Some(OrderCategory::Service), OrderSubtype::ServiceComplaints)
     );
 
-    assert_eq!(OrderType::from_str("Жалобы на обслуживание"), Ok(OrderType::NetBroken));
+    assert_eq!(
+        OrderType::from_str("Жалобы на обслуживание"),
+        order_type(None, OrderSubtype::ServiceComplaints)
+    );
 
     assert_eq!(
         OrderType::from_str("Сервис-Тюнер ДЕМОНТАЖ"),
-        Ok(OrderType::TunerRemoval)
+        order_type(Some(OrderCategory::Service), OrderSubtype::TunerRemoval)
     );
 
-    assert_eq!(OrderType::from_str("Тюнер ДЕМОНТАЖ"), Ok(OrderType::TunerRemoval));
-
     assert_eq!(
         OrderType::from_str("Сервис-Тюнер ЗАМЕНА"),
-        Ok(OrderType::TunerReplacement)
+        order_type(Some(OrderCategory::Service), OrderSubtype::TunerReplacement)
     );
 
-    assert_eq!(OrderType::from_str("Тюнер ЗАМЕНА"), Ok(OrderType::TunerReplacement));
-
     assert_eq!(
         OrderType::from_str("Сервис-Тюнер УСТАНОВКА"),
-        Ok(OrderType::TunerInstallation)
+        order_type(Some(OrderCategory::Service), OrderSubtype::TunerInstallation)
     );
 
-    assert_eq!(OrderType::from_str("Тюнер УСТАНОВКА"), Ok(OrderType::TunerInstallation));
-
-    assert_eq!(OrderType::from_str("Сервис-Гігабіт"), Ok(OrderType::NetGigabit));
-
-    assert_eq!(OrderType::from_str("Гігабіт"), Ok(OrderType::NetGigabit));
+    assert_eq!(
+        OrderType::from_str("Сервис-Гігабіт"),
+        order_type(Some(OrderCategory::Service), OrderSubtype::Gigabit)
+    );
 
     assert_eq!(
         OrderType::from_str("Актив-Восстановление"),
-        Ok(OrderType::NetRecoveryActive)
+        order_type(Some(OrderCategory::Active), OrderSubtype::Recovery)
     );
 
     assert_eq!(
-        OrderType::from_str("Пассив-Восстановление"),
-        Ok(OrderType::NetRecoveryPassive)
+        OrderType::from_str("Пассив-Новое подключение"),
+        order_type(Some(OrderCategory::Passive), OrderSubtype::NewConnection)
     );
 
-    assert_eq!(OrderType::from_str("Восстановление"), Ok(OrderType::NetRecoveryUnknown));
-
     assert_eq!(
-        OrderType::from_str("Актив-Новое подключение"),
-        Ok(OrderType::NetNewActive)
+        OrderType::from_str("Новое подключение"),
+        order_type(None, OrderSubtype::NewConnection)
     );
 
     assert_eq!(
-        OrderType::from_str("Пассив-Новое подключение"),
-        Ok(OrderType::NetNewPassive)
+        OrderType::from_str("Сервис-Мастер\nКорпоративный"),
+        order_type(
+            Some(OrderCategory::Service),
+            OrderSubtype::Master(Some("Корпоративный".to_string()))
+        )
     );
 
-    assert_eq!(OrderType::from_str("Новое подключение"), Ok(OrderType::NetNewUnknown));
+    assert_eq!(
+        OrderType::from_str("Мастер"),
+        order_type(None, OrderSubtype::Master(None))
+    );
 
     assert_eq!(
-        OrderType::from_str("Сервис-Жалобы на обслуживание"),
-        Ok(OrderType::NetBroken)
+        OrderType::from_str("Пассив-Переезд"),
+        order_type(Some(OrderCategory::Passive), OrderSubtype::Relocation)
     );
 
-    assert_eq!(OrderType::from_str("Жалобы на обслуживание"), Ok(OrderType::NetBroken));
+    assert_eq!(
+        OrderType::from_str("Сервис-Жалобы по включениям"),
+        order_type(Some(OrderCategory::Service), OrderSubtype::ConnectionComplaints)
+    );
 
     assert_eq!(
-        OrderType::from_str("Сервис-Мастер\nКорпоративный"),
-        Ok(OrderType::OthersMaster)
+        OrderType::from_str("???"),
+        order_type(None, OrderSubtype::Unrecognized("???".to_string()))
     );
 
-    assert_eq!(OrderType::from_str("Мастер"), Ok(OrderType::OthersMaster));
+    assert_eq!(
+        OrderType::from_str(""),
+        Err(OrderTypeError::InvalidOrderType("".to_string()))
+    );
 
-    assert_eq!(OrderType::from_str("Актив-Переезд"), Ok(OrderType::NetRelocationActive));
+    assert_eq!(
+        OrderType::from_str("Сервис-"),
+        Err(OrderTypeError::InvalidOrderType("".to_string()))
+    );
+}
 
+#[test]
+fn from_subtype() {
     assert_eq!(
-        OrderType::from_str("Пассив-Переезд"),
-        Ok(OrderType::NetRelocationPassive)
+        OrderType::from_subtype(Some(OrderCategory::Active), " Новое подключение "),
+        order_type(Some(OrderCategory::Active), OrderSubtype::NewConnection)
+    );
+    assert_eq!(
+        OrderType::from_subtype(None, " "),
+        Err(OrderTypeError::InvalidOrderType("".to_string()))
     );
+}
 
-    assert_eq!(OrderType::from_str("Переезд"), Ok(OrderType::NetRelocationUnknown));
+#[test]
+fn unrecognized_is_lossless() {
+    assert_eq!(
+        OrderType::from_str("Сервис-Роутер УСТАНОВКА"),
+        order_type(
+            Some(OrderCategory::Service),
+            OrderSubtype::Unrecognized("Роутер УСТАНОВКА".to_string())
+        )
+    );
 
+    // Combination which doesn't make sense is kept as is
     assert_eq!(
-        OrderType::from_str("Сервис-Жалобы по включениям"),
-        Ok(OrderType::SomeBitchComplained)
+        OrderType::from_str("Актив-Гігабіт"),
+        order_type(Some(OrderCategory::Active), OrderSubtype::Gigabit)
     );
 
     assert_eq!(
-        OrderType::from_str("Жалобы по включениям"),
-        Ok(OrderType::SomeBitchComplained)
+        OrderType::from_str("Корпоратив-Новое подключение"),
+        order_type(
+            Some(OrderCategory::Unrecognized("Корпоратив".to_string())),
+            OrderSubtype::NewConnection
+        )
     );
+}
 
+#[test]
+fn round_trip() {
+    let order_types = [
+        OrderType {
+            category: Some(OrderCategory::Unrecognized("X".to_string())),
+            subtype:  OrderSubtype::NewConnection,
+        },
+        OrderType {
+            category: Some(OrderCategory::Active),
+            subtype:  OrderSubtype::Gigabit,
+        },
+        OrderType {
+            category: Some(OrderCategory::Service),
+            subtype:  OrderSubtype::Master(Some("Корпоративный".to_string())),
+        },
+        OrderType {
+            category: None,
+            subtype:  OrderSubtype::Unrecognized("Роутер УСТАНОВКА".to_string()),
+        },
+    ];
+
+    for order_type in order_types {
+        assert_eq!(OrderType::from_str(&order_type.to_string()), Ok(order_type));
+    }
+}
+
+#[test]
+fn category_and_subtype() {
+    assert_eq!(OrderCategory::from(" Актив "), OrderCategory::Active);
     assert_eq!(
-        OrderType::from_str("???"),
-        Err(OrderTypeError::InvalidOrderType("???".to_string()))
+        OrderSubtype::from("Мастер\nКорпоративный"),
+        OrderSubtype::Master(Some("Корпоративный".to_string()))
+    );
+    assert_eq!(OrderSubtype::Gigabit.to_string(), "Гігабіт");
+    assert_eq!(
+        OrderSubtype::Master(Some("Корпоративный".to_string())).to_string(),
+        "Мастер Корпоративный"
     );
+    assert_eq!(OrderCategory::Service.to_string(), "Сервис");
 }
@@ -0,0 +1,35 @@
+use scraper::{Html, Selector};
+
+use crate::data::service::{Service, ServiceError};
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+
+fn service(row: &str) -> Result<Service, ServiceError> {
+    let html = Html::parse_fragment(&format!("<table>{row}</table>"));
+    let selector = Selector::parse("tr").unwrap();
+
+    Service::from(html.select(&selector).next().unwrap())
+}
+
+#[test]
+fn from_row() {
+    assert_eq!(
+        service(r#"<tr class="servicesrow" rowid="5"><td>Сервис на сети FTTB</td><td></td></tr>"#),
+        Ok(Service {
+            id:     5,
+            name:   "Сервис на сети FTTB".to_string(),
+            source: None,
+        })
+    );
+    assert_eq!(
+        service(r#"<tr class="servicesrow"><td>Сервис на сети FTTB</td></tr>"#),
+        Err(ServiceError::NoRowId)
+    );
+    assert_eq!(
+        service(r#"<tr class="servicesrow" rowid="█"><td>Сервис на сети FTTB</td></tr>"#),
+        Err(ServiceError::InvalidRowId(SerializableIntErrorKind::InvalidDigit))
+    );
+    assert_eq!(
+        service(r#"<tr class="servicesrow" rowid="5"><td> </td></tr>"#),
+        Err(ServiceError::NoName)
+    );
+}
@@ -0,0 +1,32 @@
+use std::str::FromStr;
+
+use crate::data::aggregation_point::{AggregationPoint, AggregationPointError};
+use crate::data::technology::{Technology, TechnologyError};
+
+#[test]
+fn technology_from_str() {
+    assert_eq!(Technology::from_str("FTTB"), Ok(Technology::FTTB));
+    assert_eq!(Technology::from_str(" fttb "), Ok(Technology::FTTB));
+    assert_eq!(Technology::from_str("xDSL"), Ok(Technology::XDSL));
+    assert_eq!(
+        Technology::from_str("FTTX"),
+        Err(TechnologyError::InvalidTechnology("FTTX".to_string()))
+    );
+}
+
+#[test]
+fn aggregation_point_from_str() {
+    let aggregation_point = AggregationPoint::from_str("FTTB_Запоріжжя").unwrap();
+
+    assert_eq!(aggregation_point, AggregationPoint {
+        technology: Technology::FTTB,
+        name:       "Запоріжжя".to_string(),
+    });
+    assert_eq!(aggregation_point.to_string(), "FTTB_Запоріжжя");
+    assert_eq!(
+        AggregationPoint::from_str("PON_Київ"),
+        Err(AggregationPointError::InvalidTechnology(
+            TechnologyError::InvalidTechnology("PON".to_string())
+        ))
+    );
+}
@@ -1,6 +1,7 @@
-use chrono::NaiveTime;
+use chrono::{DateTime, NaiveDate, NaiveTime};
 
 use crate::data::time_constrains::{TimeConstrains, TimeConstrainsError};
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
 
 #[test]
 fn should_match() {
@@ -93,3 +94,27 @@ fn invalid_naive_time() {
         Err(TimeConstrainsError::InvalidNaiveTime)
     );
 }
+
+#[test]
+fn starts_at() {
+    let time_constrains = TimeConstrains::from("с 11:30 до 11:59").unwrap();
+
+    assert_eq!(
+        time_constrains.starts_at(NaiveDate::from_ymd_opt(2024, 5, 17).unwrap()),
+        Ok(DateTime::parse_from_rfc3339("2024-05-17 11:30:00+03:00").unwrap())
+    );
+    assert_eq!(
+        time_constrains.starts_at(NaiveDate::from_ymd_opt(2024, 1, 17).unwrap()),
+        Ok(DateTime::parse_from_rfc3339("2024-01-17 11:30:00+02:00").unwrap())
+    );
+}
+
+#[test]
+fn starts_at_dst_gap() {
+    let time_constrains = TimeConstrains::from("с 03:00 до 03:29").unwrap();
+
+    assert_eq!(
+        time_constrains.starts_at(NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()),
+        Err(SerializableParseErrorKind::Impossible)
+    );
+}
@@ -0,0 +1,19 @@
+use std::fs;
+
+use paste::paste;
+
+use crate::incidents::Incidents;
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+use crate::tests::test;
+
+test! {
+    "incidents", Incidents;
+    id: { Some(Err(SerializableIntErrorKind::InvalidDigit)), invalid_u32, first; },
+    created: { Some(Err(SerializableParseErrorKind::Invalid)), invalid_datetime, first; },
+}
+
+#[test]
+fn empty() {
+    assert_eq!(Incidents::from("").0, vec![]);
+}
@@ -0,0 +1,3 @@
+mod invalid;
+mod subtypes;
+mod valid_1;
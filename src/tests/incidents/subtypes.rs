@@ -0,0 +1,86 @@
+use std::fs;
+
+use crate::incidents::incident_draft::{IncidentDraft, IncidentDraftError};
+use crate::incidents::incident_subtypes::{IncidentSubtype, IncidentSubtypes};
+
+fn subtypes() -> IncidentSubtypes {
+    let html = fs::read_to_string("src/tests/assets/incident_subtypes/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    IncidentSubtypes::from(&html)
+}
+
+#[test]
+fn catalogue() {
+    let subtypes = subtypes();
+
+    assert_eq!(subtypes.0.len(), 5);
+    assert_eq!(subtypes.incident_types(), vec!["Монтаж", "Абонент"]);
+    assert_eq!(
+        subtypes.find("Отказ от подключения"),
+        Some(&IncidentSubtype {
+            id:            12,
+            name:          "Отказ от подключения".to_string(),
+            incident_type: Some("Абонент".to_string()),
+        })
+    );
+    assert_eq!(
+        subtypes.get(3).map(|subtype| subtype.name.as_str()),
+        Some("Нет доступа в подъезд")
+    );
+    assert_eq!(subtypes.get(1), None);
+}
+
+#[test]
+fn without_groups() {
+    let subtypes = IncidentSubtypes::from(r#"<option value=""></option><option value="5">Другое</option>"#);
+
+    assert_eq!(subtypes.0, vec![IncidentSubtype {
+        id:            5,
+        name:          "Другое".to_string(),
+        incident_type: None,
+    }]);
+}
+
+#[test]
+fn validate() {
+    let subtypes = subtypes();
+    let draft = |subtype: Option<u32>, text: &str| IncidentDraft {
+        subtype,
+        text: text.to_string(),
+        related_order_id: None,
+    };
+
+    assert_eq!(draft(Some(3), "Домофон не работает").validate(&subtypes), Ok(()));
+    assert_eq!(
+        draft(Some(3), " ").validate(&subtypes),
+        Err(IncidentDraftError::EmptyText)
+    );
+    assert_eq!(
+        draft(None, "Домофон не работает").validate(&subtypes),
+        Err(IncidentDraftError::NoSubtype)
+    );
+    assert_eq!(
+        draft(Some(1), "Домофон не работает").validate(&subtypes),
+        Err(IncidentDraftError::UnknownSubtype(1))
+    );
+    assert_eq!(
+        draft(None, "Домофон не работает").validate(&IncidentSubtypes::default()),
+        Ok(())
+    );
+}
+
+#[test]
+fn payload() {
+    let draft = IncidentDraft {
+        subtype:          Some(3),
+        text:             "Домофон не работает".to_string(),
+        related_order_id: None,
+    };
+
+    assert_eq!(
+        draft.payload(950207),
+        "action=createIncident&id=950207&text=%D0%94%D0%BE%D0%BC%D0%BE%D1%84%D0%BE%D0%BD+%D0%BD%D0%B5+%D1%80%D0%B0%D0%\
+         B1%D0%BE%D1%82%D0%B0%D0%B5%D1%82&link=&subtype=3"
+    );
+}
@@ -0,0 +1,58 @@
+use std::fs;
+
+use chrono::DateTime;
+
+use crate::incidents::Incidents;
+
+#[test]
+fn should_guarantee() {
+    let html =
+        fs::read_to_string("src/tests/assets/incidents/valid/1.html").expect("Should have been able to read the file");
+
+    let incidents = Incidents::from(&html);
+
+    assert_eq!(incidents.0.len(), 2);
+    for incident in incidents.0 {
+        assert!(incident.into_guaranteed().is_some());
+    }
+}
+
+#[test]
+fn incident_31874() {
+    let html =
+        fs::read_to_string("src/tests/assets/incidents/valid/1.html").expect("Should have been able to read the file");
+
+    let incidents = Incidents::from(&html);
+    let incident = incidents.0.first().unwrap();
+
+    assert_eq!(incident.id, Some(Ok(31874)));
+    assert_eq!(incident.incident_type, Some("Монтаж".to_string()));
+    assert_eq!(incident.subtype, Some("Нет доступа в подъезд".to_string()));
+    assert_eq!(incident.status, Some("Закрыт".to_string()));
+    assert_eq!(
+        incident.description,
+        Some("Домофон не работает, абонент не отвечает Перезвонить вечером".to_string())
+    );
+    assert_eq!(
+        incident.created,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-14 10:12:31+03:00").unwrap()))
+    );
+    assert_eq!(
+        incident.updated,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-15 09:01:02+03:00").unwrap()))
+    );
+    assert_eq!(incident.author, Some("████ █████ █████████".to_string()));
+}
+
+#[test]
+fn incident_31901() {
+    let html =
+        fs::read_to_string("src/tests/assets/incidents/valid/1.html").expect("Should have been able to read the file");
+
+    let incidents = Incidents::from(&html);
+    let incident = incidents.0.last().unwrap();
+
+    assert_eq!(incident.id, Some(Ok(31901)));
+    assert_eq!(incident.subtype, None);
+    assert_eq!(incident.updated, None);
+}
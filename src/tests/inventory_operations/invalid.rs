@@ -0,0 +1,33 @@
+use std::fs;
+
+use crate::inventory_operations::inventory_operation::InventoryDirectionError;
+use crate::inventory_operations::InventoryOperations;
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+#[test]
+fn invalid_fields() {
+    let json = fs::read_to_string("src/tests/assets/inventory_operations/invalid/1.json")
+        .expect("Should have been able to read the file");
+
+    let inventory_operations = InventoryOperations::from(&json);
+    let operation = inventory_operations.0.first().unwrap();
+
+    assert_eq!(operation.quantity, Some(Err(SerializableIntErrorKind::InvalidDigit)));
+    assert_eq!(
+        operation.direction,
+        Some(Err(InventoryDirectionError::InvalidDirection("Передача".to_string())))
+    );
+    assert_eq!(operation.datetime, Some(Err(SerializableParseErrorKind::Invalid)));
+    assert_eq!(operation.user, None);
+    assert!(operation.clone().into_guaranteed().is_none());
+}
+
+#[test]
+fn not_json() {
+    assert_eq!(
+        InventoryOperations::from("<form id=\"logoform\"></form>"),
+        InventoryOperations::default()
+    );
+    assert_eq!(InventoryOperations::from("[]"), InventoryOperations::default());
+}
@@ -0,0 +1,2 @@
+mod invalid;
+mod valid_1;
@@ -0,0 +1,54 @@
+use std::fs;
+
+use chrono::DateTime;
+
+use crate::inventory_operations::inventory_operation::{InventoryDirection, InventoryOperation};
+use crate::inventory_operations::InventoryOperations;
+
+#[test]
+fn should_guarantee() {
+    let json = fs::read_to_string("src/tests/assets/inventory_operations/valid/1.json")
+        .expect("Should have been able to read the file");
+
+    let inventory_operations = InventoryOperations::from(&json);
+
+    assert_eq!(inventory_operations.0.len(), 3);
+    for operation in inventory_operations.0 {
+        assert!(operation.into_guaranteed().is_some());
+    }
+}
+
+#[test]
+fn operations() {
+    let json = fs::read_to_string("src/tests/assets/inventory_operations/valid/1.json")
+        .expect("Should have been able to read the file");
+
+    let inventory_operations = InventoryOperations::from(&json);
+
+    assert_eq!(inventory_operations.0, vec![
+        InventoryOperation {
+            item:      Some("Роутер Huawei WS5200".to_string()),
+            serial:    Some("2102353GYR10L7001234".to_string()),
+            quantity:  Some(Ok(1)),
+            direction: Some(Ok(InventoryDirection::Installed)),
+            datetime:  Some(Ok(DateTime::parse_from_rfc3339("2024-05-16 14:10:02+03:00").unwrap())),
+            user:      Some("████ █████ █████████".to_string()),
+        },
+        InventoryOperation {
+            item:      Some("Кабель UTP 5e".to_string()),
+            serial:    None,
+            quantity:  Some(Ok(25)),
+            direction: Some(Ok(InventoryDirection::WrittenOff)),
+            datetime:  Some(Ok(DateTime::parse_from_rfc3339("2024-05-16 14:10:35+03:00").unwrap())),
+            user:      Some("████ █████ █████████".to_string()),
+        },
+        InventoryOperation {
+            item:      Some("ONU ZTE F612".to_string()),
+            serial:    Some("ZTEGC8F41A22".to_string()),
+            quantity:  Some(Ok(1)),
+            direction: Some(Ok(InventoryDirection::Dismantled)),
+            datetime:  Some(Ok(DateTime::parse_from_rfc3339("2024-05-16 14:11:40+03:00").unwrap())),
+            user:      Some("████ █████ █████████".to_string()),
+        },
+    ]);
+}
@@ -0,0 +1,58 @@
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::builder::NsgBuilder;
+use crate::error::NsgError;
+use crate::tests::read_request;
+use crate::Nsg;
+
+#[test]
+fn check_action_error() {
+    assert_eq!(Nsg::check_action_error(r#"{"error":null}"#), Ok(()));
+    assert_eq!(Nsg::check_action_error(r#"{"result":"ok"}"#), Ok(()));
+    assert_eq!(Nsg::check_action_error(""), Ok(()));
+    assert_eq!(Nsg::check_action_error("<tr class='tablerrow'></tr>"), Ok(()));
+    assert_eq!(
+        Nsg::check_action_error(r#"{"error":"Заявка уже закрыта"}"#),
+        Err(NsgError::ActionFailed("Заявка уже закрыта".to_string()))
+    );
+    assert_eq!(
+        Nsg::check_action_error(r#"{"error":403}"#),
+        Err(NsgError::ActionFailed("403".to_string()))
+    );
+}
+
+#[test]
+fn rollback_succeeds_without_recorded_reason() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let root = format!("http://{}", listener.local_addr().unwrap());
+    let rollbacks = Arc::new(AtomicUsize::new(0));
+
+    let server_rollbacks = rollbacks.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+
+            // Rollback succeeds, while everything needed to add the comment fails
+            let response = if read_request(&mut stream).contains("action=rollbackRequest") {
+                server_rollbacks.fetch_add(1, Ordering::SeqCst);
+                "HTTP/1.1 200 OK\r\nContent-Length: 14\r\nConnection: close\r\n\r\n{\"error\":null}"
+            } else {
+                "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            };
+
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    let nsg = NsgBuilder::new(root, "client".to_string(), "x.y".to_string())
+        .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+        .unwrap();
+
+    assert_eq!(
+        tokio_test::block_on(nsg.rollback_request(950207, "Абонент передумал")),
+        Ok(())
+    );
+    assert_eq!(rollbacks.load(Ordering::SeqCst), 1);
+}
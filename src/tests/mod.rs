@@ -1,9 +1,28 @@
 mod basic_search;
 mod brief_request;
+mod builder;
 mod data;
+mod incidents;
+mod inventory_operations;
+mod methods;
+mod new_comment;
+mod on_demand_info;
+mod rate_limit;
+mod request_history;
+mod request_instances;
+mod router_check;
+mod sbms_history;
+mod service_hint;
+mod session;
+mod staircases;
+mod table_query;
+mod user;
 mod view_request;
 mod work_schedule;
 
+use std::io::Read;
+use std::net::TcpStream;
+
 macro_rules! test {
     ($set:expr, $parser:ident; $($var:ident: { $($expected:expr, $name:ident;)* }),* $(,)?) => {
         $($(paste! {
@@ -46,3 +65,37 @@ macro_rules! test {
 }
 
 use test;
+
+/// Reads headers and body of the request
+pub(crate) fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buffer = [0; 4096];
+
+    loop {
+        let read = stream.read(&mut buffer).unwrap();
+        request.extend_from_slice(&buffer[..read]);
+
+        if read == 0 {
+            return String::from_utf8_lossy(&request).to_string();
+        }
+
+        let text = String::from_utf8_lossy(&request).to_string();
+        let Some((headers, body)) = text.split_once("\r\n\r\n") else {
+            continue;
+        };
+
+        let content_length = headers
+            .lines()
+            .find_map(|line| {
+                line.to_lowercase()
+                    .strip_prefix("content-length: ")?
+                    .parse::<usize>()
+                    .ok()
+            })
+            .unwrap_or(0);
+
+        if body.len() >= content_length {
+            return text;
+        }
+    }
+}
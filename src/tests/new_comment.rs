@@ -0,0 +1,113 @@
+use chrono::DateTime;
+
+use crate::data::full_comment::FullComment;
+use crate::data::internal_status::InternalStatus;
+use crate::new_comment::{Attachment, NewComment};
+use crate::payload::{MultipartValue, RequestBody};
+
+fn attachment(file_name: &str) -> Attachment {
+    Attachment {
+        file_name: file_name.to_string(),
+        mime:      Some("image/jpeg".to_string()),
+        content:   vec![0xFF, 0xD8, 0xFF],
+    }
+}
+
+fn field_names(body: &RequestBody) -> Vec<&'static str> {
+    match body {
+        RequestBody::Multipart(fields) => fields.iter().map(|(name, _)| *name).collect(),
+        RequestBody::UrlEncoded(_) => panic!("Comment should be sent as multipart"),
+    }
+}
+
+#[test]
+fn body_without_attachment() {
+    let comment = NewComment {
+        text:       "Перезвонить вечером".to_string(),
+        attachment: None,
+    };
+
+    assert_eq!(field_names(&comment.body(950207)), vec!["action", "id", "comment"]);
+}
+
+#[test]
+fn body_with_attachment() {
+    let comment = NewComment {
+        text:       "Полностью заменил кабель".to_string(),
+        attachment: Some(attachment("1.jpg")),
+    };
+
+    let body = comment.body(950207);
+
+    assert_eq!(field_names(&body), vec!["action", "id", "comment", "commentfile"]);
+
+    let RequestBody::Multipart(fields) = &body else {
+        unreachable!()
+    };
+    assert!(matches!(&fields[1].1, MultipartValue::Text(id) if id == "950207"));
+    assert!(matches!(&fields[3].1, MultipartValue::File(file) if file.file_name == "1.jpg"));
+}
+
+fn full_comment(text: Option<&str>, datetime: &str) -> FullComment {
+    FullComment {
+        text:            text.map(str::to_string),
+        user:            "████ █████ █████████".to_string(),
+        datetime:        DateTime::parse_from_rfc3339(datetime).unwrap(),
+        internal_status: InternalStatus::Completed,
+    }
+}
+
+#[test]
+fn matches() {
+    let comment = NewComment {
+        text:       "Полностью заменил кабель\nпоменял порт".to_string(),
+        attachment: None,
+    };
+    let matches = |text| comment.matches(&full_comment(text, "2024-05-16 14:17:49+03:00"));
+
+    assert!(matches(Some("Полностью заменил кабель")));
+    assert!(matches(Some("Полностью  заменил кабель поменял порт")));
+    assert!(!matches(Some("Перезвонить вечером")));
+    assert!(!matches(Some(" ")));
+    assert!(!matches(None));
+}
+
+#[test]
+fn find_new() {
+    let comment = NewComment {
+        text:       "Полностью заменил кабель".to_string(),
+        attachment: None,
+    };
+    let old = full_comment(Some("Полностью заменил кабель"), "2024-05-16 14:17:49+03:00");
+    let other = full_comment(Some("Перезвонить вечером"), "2024-05-16 14:18:00+03:00");
+    // Portal's clock may be behind, so new comment can be dated earlier
+    let new = full_comment(Some("Полностью заменил кабель"), "2024-05-16 14:17:40+03:00");
+
+    assert_eq!(
+        comment.find_new(vec![old.clone()], vec![old.clone(), other.clone(), new.clone()]),
+        Some(new)
+    );
+    assert_eq!(comment.find_new(vec![old.clone()], vec![old.clone(), other]), None);
+    // Same comment sent twice in the same second
+    assert_eq!(
+        comment.find_new(vec![old.clone()], vec![old.clone(), old.clone()]),
+        Some(old)
+    );
+}
+
+#[test]
+fn describe_body() {
+    let comment = NewComment {
+        text:       "Фото".to_string(),
+        attachment: Some(attachment("router.jpg")),
+    };
+
+    assert_eq!(
+        comment.body(950207).describe(),
+        "action=\"addRequestComment\", id=\"950207\", comment=\"Фото\", commentfile=<router.jpg (3 bytes)>"
+    );
+    assert_eq!(
+        RequestBody::from("action=confirmSBMSChange&id=482954".to_string()).describe(),
+        "action=confirmSBMSChange&id=482954"
+    );
+}
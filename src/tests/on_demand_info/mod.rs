@@ -0,0 +1,23 @@
+use crate::on_demand_info::OnDemandInfo;
+
+#[test]
+fn updated() {
+    assert_eq!(OnDemandInfo::from("1\n"), OnDemandInfo {
+        updated: true,
+        message: None,
+    });
+}
+
+#[test]
+fn not_updated() {
+    assert_eq!(OnDemandInfo::from("0"), OnDemandInfo::default());
+    assert_eq!(OnDemandInfo::from(""), OnDemandInfo::default());
+}
+
+#[test]
+fn message() {
+    assert_eq!(OnDemandInfo::from("Заявка не найдена"), OnDemandInfo {
+        updated: false,
+        message: Some("Заявка не найдена".to_string()),
+    });
+}
@@ -0,0 +1,129 @@
+use std::io::{Read, Write};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::builder::{NsgBuilder, RetryPolicy};
+use crate::error::NsgError;
+use crate::payload::RequestBody;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::Nsg;
+
+/// Instance sending requests to a closed local port, so every attempt fails
+/// with connect error
+fn unreachable_nsg() -> Nsg {
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+
+    NsgBuilder::new(
+        format!("http://127.0.0.1:{port}"),
+        "client".to_string(),
+        "x.y".to_string(),
+    )
+    .rate_limit(RateLimit {
+        requests_per_second: 0,
+        max_in_flight:       1,
+    })
+    .retry_policy(RetryPolicy::new(1, Duration::from_millis(500)))
+    .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+    .unwrap()
+}
+
+#[test]
+fn spreads_requests() {
+    let rate_limiter = RateLimiter::new(&RateLimit {
+        requests_per_second: 20,
+        max_in_flight:       4,
+    });
+
+    tokio_test::block_on(async {
+        let start = Instant::now();
+
+        for _ in 0..5 {
+            drop(rate_limiter.acquire().await);
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    });
+}
+
+#[test]
+fn limits_in_flight() {
+    let rate_limiter = RateLimiter::new(&RateLimit {
+        requests_per_second: 0,
+        max_in_flight:       2,
+    });
+
+    tokio_test::block_on(async {
+        let first = rate_limiter.acquire().await;
+        let _second = rate_limiter.acquire().await;
+        assert_eq!(rate_limiter.semaphore.available_permits(), 0);
+
+        drop(first);
+        assert_eq!(rate_limiter.semaphore.available_permits(), 1);
+    });
+}
+
+#[test]
+fn zero_in_flight_is_one() {
+    let rate_limiter = RateLimiter::new(&RateLimit {
+        requests_per_second: 0,
+        max_in_flight:       0,
+    });
+
+    assert_eq!(rate_limiter.semaphore.available_permits(), 1);
+}
+
+#[test]
+fn releases_permit_before_retry_backoff() {
+    let nsg = unreachable_nsg();
+    let semaphore = &nsg.rate_limiter.as_ref().unwrap().semaphore;
+
+    tokio_test::block_on(async {
+        let (result, available_permits) =
+            futures::join!(nsg.request(nsg.construct_headers(), String::new(), None), async {
+                tokio::time::sleep(Duration::from_millis(250)).await;
+                semaphore.available_permits()
+            });
+
+        assert_eq!(result.err(), Some(NsgError::ReqwestConnectError));
+        assert_eq!(available_permits, 1);
+    });
+}
+
+#[test]
+fn holds_permit_while_reading() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let root = format!("http://{}", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let _ = stream.read(&mut [0; 4096]);
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+            .unwrap();
+    });
+
+    let nsg = NsgBuilder::new(root, "client".to_string(), "x.y".to_string())
+        .rate_limit(RateLimit {
+            requests_per_second: 0,
+            max_in_flight:       1,
+        })
+        .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+        .unwrap();
+    let semaphore = &nsg.rate_limiter.as_ref().unwrap().semaphore;
+    let body = RequestBody::UrlEncoded(String::new());
+
+    let read = |response: reqwest::Response| async move {
+        let available_permits = semaphore.available_permits();
+        Ok((response.text().await?, available_permits))
+    };
+
+    let result = tokio_test::block_on(nsg.request_body(nsg.construct_headers(), &body, None, &nsg.retry_policy, read));
+
+    assert_eq!(result, Ok(("ok".to_string(), 0)));
+    assert_eq!(semaphore.available_permits(), 1);
+}
@@ -0,0 +1,39 @@
+use crate::request_history::field_change::FieldChange;
+
+#[test]
+fn from_table() {
+    let changes = FieldChange::from_table(
+        "<table>
+            <tr><td>Статус:</td><td>Назначено</td></tr>
+            <tr><td>Монтажник:</td><td>████ █████ █████████&nbsp;</td></tr>
+            <tr><td>Время до:</td><td>2024-05-15 14:29:59&nbsp;<=&nbsp;2024-05-15 14:29:00</td></tr>
+            <tr><td></td></tr>
+        </table>",
+    );
+
+    assert_eq!(changes, vec![
+        FieldChange {
+            field:     "Статус".to_string(),
+            new_value: Some("Назначено".to_string()),
+            old_value: None,
+        },
+        FieldChange {
+            field:     "Монтажник".to_string(),
+            new_value: Some("████ █████ █████████".to_string()),
+            old_value: None,
+        },
+        FieldChange {
+            field:     "Время до".to_string(),
+            new_value: Some("2024-05-15 14:29:59".to_string()),
+            old_value: Some("2024-05-15 14:29:00".to_string()),
+        },
+    ]);
+}
+
+#[test]
+fn empty_name() {
+    assert_eq!(
+        FieldChange::from_table("<table><tr><td> </td><td>1</td></tr></table>"),
+        vec![]
+    );
+}
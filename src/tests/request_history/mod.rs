@@ -0,0 +1,3 @@
+mod field_change;
+mod valid_1;
+mod valid_4;
@@ -0,0 +1,102 @@
+use std::fs;
+
+use chrono::DateTime;
+
+use crate::request_history::field_change::FieldChange;
+use crate::request_history::OrderHistory;
+
+#[test]
+fn should_guarantee() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let order_history = OrderHistory::from(&html);
+
+    assert_eq!(order_history.0.len(), 9);
+    for entry in order_history.0 {
+        assert!(entry.into_guaranteed().is_some());
+    }
+}
+
+#[test]
+fn entry_5914732() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let order_history = OrderHistory::from(&html);
+    let entry = order_history.0.first().unwrap();
+
+    assert_eq!(entry.id, Some(Ok(5914732)));
+    assert_eq!(entry.user, Some("████ █████ █████████".to_string()));
+    assert_eq!(
+        entry.datetime,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-16 14:17:49+03:00").unwrap()))
+    );
+    assert_eq!(entry.changes, vec![
+        FieldChange {
+            field:     "Статус".to_string(),
+            new_value: Some("Выполнено".to_string()),
+            old_value: None,
+        },
+        FieldChange {
+            field:     "Дата перезвона".to_string(),
+            new_value: Some("2024-05-16".to_string()),
+            old_value: Some("2024-05-15".to_string()),
+        },
+        FieldChange {
+            field:     "Оборудование".to_string(),
+            new_value: Some("Кабель UTP 40\nКоннектор RG-45 9\nХомут-стяжка 12\nИзолента 5".to_string()),
+            old_value: None,
+        },
+    ]);
+}
+
+#[test]
+fn entry_5913102() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let order_history = OrderHistory::from(&html);
+    let entry = &order_history.0[1];
+
+    assert_eq!(entry.id, Some(Ok(5913102)));
+    assert_eq!(entry.changes, vec![
+        FieldChange {
+            field:     "Статус".to_string(),
+            new_value: Some("Назначено".to_string()),
+            old_value: None,
+        },
+        FieldChange {
+            field:     "Диспетчер".to_string(),
+            new_value: Some("████████ ██████ ███████████".to_string()),
+            old_value: Some("██████ █████ ███████████".to_string()),
+        },
+        FieldChange {
+            field:     "Время от".to_string(),
+            new_value: Some("2024-05-16 11:00:00".to_string()),
+            old_value: Some("2024-05-16 14:00:00".to_string()),
+        },
+        FieldChange {
+            field:     "Время до".to_string(),
+            new_value: Some("2024-05-16 11:29:59".to_string()),
+            old_value: Some("2024-05-16 14:29:00".to_string()),
+        },
+    ]);
+}
+
+#[test]
+fn system_entry() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let order_history = OrderHistory::from(&html);
+    let entry = order_history.0.last().unwrap();
+
+    assert_eq!(entry.id, Some(Ok(5909993)));
+    assert_eq!(entry.user, Some("Система".to_string()));
+    assert_eq!(
+        entry.datetime,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-13 17:05:51+03:00").unwrap()))
+    );
+    assert_eq!(entry.changes.len(), 1);
+}
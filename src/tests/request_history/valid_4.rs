@@ -0,0 +1,21 @@
+use std::fs;
+
+use crate::request_history::OrderHistory;
+
+#[test]
+fn should_guarantee() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/4.html")
+        .expect("Should have been able to read the file");
+
+    let order_history = OrderHistory::from(&html);
+
+    assert_eq!(order_history.0.len(), 4);
+    for entry in order_history.0 {
+        assert!(entry.into_guaranteed().is_some());
+    }
+}
+
+#[test]
+fn not_view_request() {
+    assert_eq!(OrderHistory::from("<form id=\"logoform\"></form>").0, vec![]);
+}
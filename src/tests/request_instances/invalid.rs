@@ -0,0 +1,32 @@
+use std::fs;
+
+use crate::data::status::StatusError;
+use crate::request_instances::RequestInstances;
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+
+#[test]
+fn invalid_fields() {
+    let json = fs::read_to_string("src/tests/assets/request_instances/invalid/1.json")
+        .expect("Should have been able to read the file");
+
+    let request_instances = RequestInstances::from(&json);
+    let instance = request_instances.0.first().unwrap();
+
+    assert_eq!(instance.instance, Some(Err(SerializableIntErrorKind::InvalidDigit)));
+    assert_eq!(instance.order_id, Some(Err(SerializableIntErrorKind::InvalidDigit)));
+    assert_eq!(
+        instance.status,
+        Some(Err(StatusError::InvalidStatus("Закрыта".to_string())))
+    );
+    assert_eq!(instance.created, Some(Err(SerializableParseErrorKind::Invalid)));
+    assert!(instance.clone().into_guaranteed().is_none());
+}
+
+#[test]
+fn not_json() {
+    assert_eq!(
+        RequestInstances::from("<form id=\"logoform\"></form>"),
+        RequestInstances::default()
+    );
+}
@@ -0,0 +1,45 @@
+use std::fs;
+
+use chrono::DateTime;
+
+use crate::data::status::Status;
+use crate::request_instances::request_instance::RequestInstance;
+use crate::request_instances::RequestInstances;
+
+#[test]
+fn should_guarantee() {
+    let json = fs::read_to_string("src/tests/assets/request_instances/valid/1.json")
+        .expect("Should have been able to read the file");
+
+    let request_instances = RequestInstances::from(&json);
+
+    assert_eq!(request_instances.0.len(), 2);
+    for instance in request_instances.0 {
+        assert!(instance.into_guaranteed().is_some());
+    }
+}
+
+#[test]
+fn instances() {
+    let json = fs::read_to_string("src/tests/assets/request_instances/valid/1.json")
+        .expect("Should have been able to read the file");
+
+    let request_instances = RequestInstances::from(&json);
+
+    assert_eq!(request_instances.0, vec![
+        RequestInstance {
+            instance: Some(Ok(1)),
+            order_id: Some(Ok(13351186)),
+            status:   Some(Ok(Status::Completed)),
+            created:  Some(Ok(DateTime::parse_from_rfc3339("2024-05-13 16:16:00+03:00").unwrap())),
+            closed:   Some(Ok(DateTime::parse_from_rfc3339("2024-05-16 14:17:49+03:00").unwrap())),
+        },
+        RequestInstance {
+            instance: Some(Ok(2)),
+            order_id: Some(Ok(13398211)),
+            status:   Some(Ok(Status::InWork)),
+            created:  Some(Ok(DateTime::parse_from_rfc3339("2024-05-20 09:02:41+03:00").unwrap())),
+            closed:   None,
+        },
+    ]);
+}
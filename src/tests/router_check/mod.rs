@@ -0,0 +1,63 @@
+use std::fs;
+
+use chrono::DateTime;
+
+use crate::router_check::router_activation::RouterActivation;
+use crate::router_check::RouterCheck;
+
+#[test]
+fn valid_1() {
+    let json = fs::read_to_string("src/tests/assets/router_check/valid/1.json")
+        .expect("Should have been able to read the file");
+
+    let router_check = RouterCheck::from(&json);
+
+    assert_eq!(
+        router_check.router,
+        Some(RouterActivation {
+            activated: true,
+            device_id: Some("00:1A:2B:3C:4D:5E".to_string()),
+            last_seen: Some(Ok(DateTime::parse_from_rfc3339("2024-05-16 14:02:11+03:00").unwrap())),
+            message:   Some("2024-05-16 14:02:11 MAC: 00:1a:2b:3c:4d:5e".to_string()),
+        })
+    );
+    assert_eq!(router_check.router_pro, Some(RouterActivation::default()));
+    assert!(router_check.is_online());
+}
+
+#[test]
+fn valid_2() {
+    let json = fs::read_to_string("src/tests/assets/router_check/valid/2.json")
+        .expect("Should have been able to read the file");
+
+    let router_check = RouterCheck::from(&json);
+
+    assert_eq!(router_check.router, Some(RouterActivation::default()));
+    assert_eq!(
+        router_check.router_pro,
+        Some(RouterActivation {
+            activated: true,
+            device_id: Some("ZTEGC8F41A22".to_string()),
+            last_seen: Some(Ok(DateTime::parse_from_rfc3339("2024-05-20 09:15:47+03:00").unwrap())),
+            message:   Some("SN: ZTEGC8F41A22 2024-05-20 09:15:47".to_string()),
+        })
+    );
+}
+
+#[test]
+fn not_activated() {
+    let router_check = RouterCheck::from(r#"{"1":0,"2":"0"}"#);
+
+    assert_eq!(router_check.router, Some(RouterActivation::default()));
+    assert_eq!(router_check.router_pro, Some(RouterActivation::default()));
+    assert!(!router_check.is_online());
+}
+
+#[test]
+fn invalid_json() {
+    assert_eq!(
+        RouterCheck::from("<form id=\"logoform\"></form>"),
+        RouterCheck::default()
+    );
+    assert_eq!(RouterCheck::from("{}"), RouterCheck::default());
+}
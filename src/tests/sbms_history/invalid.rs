@@ -0,0 +1,20 @@
+use std::fs;
+
+use paste::paste;
+
+use crate::sbms_history::SbmsHistory;
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+use crate::tests::test;
+
+test! {
+    "sbms_history", SbmsHistory;
+    id: { Some(Err(SerializableIntErrorKind::InvalidDigit)), invalid_u32, first; },
+    datetime: { Some(Err(SerializableParseErrorKind::Invalid)), invalid_datetime, first; },
+    attention: { Some(Err(SerializableIntErrorKind::InvalidDigit)), invalid_u8, first; },
+}
+
+#[test]
+fn empty() {
+    assert_eq!(SbmsHistory::from("").0, vec![]);
+}
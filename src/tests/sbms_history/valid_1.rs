@@ -0,0 +1,51 @@
+use std::fs;
+
+use chrono::DateTime;
+
+use crate::sbms_history::SbmsHistory;
+
+#[test]
+fn should_guarantee() {
+    let html = fs::read_to_string("src/tests/assets/sbms_history/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let sbms_history = SbmsHistory::from(&html);
+
+    assert_eq!(sbms_history.0.len(), 3);
+    for entry in sbms_history.0 {
+        assert!(entry.into_guaranteed().is_some());
+    }
+}
+
+#[test]
+fn entry_482954() {
+    let html = fs::read_to_string("src/tests/assets/sbms_history/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let sbms_history = SbmsHistory::from(&html);
+    let entry = sbms_history.0.get(1).unwrap();
+
+    assert_eq!(entry.id, Some(Ok(482954)));
+    assert_eq!(
+        entry.datetime,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-15 11:02:13+03:00").unwrap()))
+    );
+    assert_eq!(entry.status, Some("Перенос".to_string()));
+    assert_eq!(entry.comment, Some("Абонент просит перенести на 16.05".to_string()));
+    assert_eq!(entry.attention, Some(Ok(1)));
+    assert_eq!(entry.confirmed_by, Some("████ █████ █████████".to_string()));
+    assert!(!entry.needs_confirmation());
+}
+
+#[test]
+fn unconfirmed() {
+    let html = fs::read_to_string("src/tests/assets/sbms_history/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let sbms_history = SbmsHistory::from(&html);
+    let unconfirmed = sbms_history.unconfirmed();
+
+    assert_eq!(unconfirmed.len(), 1);
+    assert_eq!(unconfirmed[0].id, Some(Ok(483310)));
+    assert_eq!(unconfirmed[0].comment, None);
+}
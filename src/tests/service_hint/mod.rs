@@ -0,0 +1,40 @@
+use std::fs;
+
+use crate::service_hint::ServiceHint;
+
+#[test]
+fn valid_1() {
+    let html = fs::read_to_string("src/tests/assets/service_hint/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let service_hint = ServiceHint::from(&html);
+
+    assert_eq!(service_hint.name, Some("Домашній Інтернет + ТБ Гігабіт".to_string()));
+    assert_eq!(service_hint.speed, Some("1 Гбіт/с".to_string()));
+    assert_eq!(service_hint.tv_channels, Some(Ok(240)));
+    assert_eq!(service_hint.equipment, vec![
+        "Роутер Wi-Fi 6".to_string(),
+        "ТВ-приставка Kyivstar TV".to_string()
+    ]);
+    assert_eq!(service_hint.lines.len(), 5);
+    assert!(service_hint.needs_tuner());
+}
+
+#[test]
+fn valid_2() {
+    let html = fs::read_to_string("src/tests/assets/service_hint/valid/2.html")
+        .expect("Should have been able to read the file");
+
+    let service_hint = ServiceHint::from(&html);
+
+    assert_eq!(service_hint.name, Some("Подключение к сети FTTB".to_string()));
+    assert_eq!(service_hint.speed, Some("500 Мбит/с".to_string()));
+    assert_eq!(service_hint.tv_channels, None);
+    assert_eq!(service_hint.equipment, vec!["Роутер".to_string()]);
+    assert!(!service_hint.needs_tuner());
+}
+
+#[test]
+fn empty() {
+    assert_eq!(ServiceHint::from(""), ServiceHint::default());
+}
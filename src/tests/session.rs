@@ -0,0 +1,113 @@
+use std::io::Write;
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::builder::NsgBuilder;
+use crate::error::NsgError;
+use crate::tests::read_request;
+use crate::Nsg;
+
+/// Serves Portal on a local port. Every login issues a new session and
+/// requests without the latest one get the login page. Returns root and
+/// count of performed logins
+fn mock_portal() -> (String, Arc<AtomicUsize>) {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let root = format!("http://{}", listener.local_addr().unwrap());
+    let logins = Arc::new(AtomicUsize::new(0));
+
+    let server_logins = logins.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let logins = server_logins.clone();
+            std::thread::spawn(move || respond(stream.unwrap(), &logins));
+        }
+    });
+
+    (root, logins)
+}
+
+fn respond(mut stream: TcpStream, logins: &AtomicUsize) {
+    let request = read_request(&mut stream);
+
+    let response = if request.contains("action=user%2Flogin") {
+        let login = logins.fetch_add(1, Ordering::SeqCst) + 1;
+
+        format!(
+            "HTTP/1.1 200 OK\r\nSet-Cookie: SESSION_nsg=fresh-{login}\r\nContent-Length: 2\r\nConnection: \
+             close\r\n\r\nok"
+        )
+    } else if request.contains(&format!("SESSION_nsg=fresh-{}\r\n", logins.load(Ordering::SeqCst))) {
+        "HTTP/1.1 200 OK\r\nContent-Length: 4\r\nConnection: close\r\n\r\ndata".to_string()
+    } else {
+        let body = r#"<form id="logoform"></form>"#;
+
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    };
+
+    stream.write_all(response.as_bytes()).unwrap();
+}
+
+async fn expired_nsg(root: String) -> Nsg {
+    let nsg = NsgBuilder::new(root, "client".to_string(), "x.y".to_string())
+        .build_with_creds("login".to_string(), "password_hash".to_string())
+        .await
+        .unwrap();
+
+    *nsg.session.write().unwrap() = Some("expired".to_string());
+
+    nsg
+}
+
+#[test]
+fn relogins_and_retries() {
+    let (root, logins) = mock_portal();
+
+    tokio_test::block_on(async {
+        let nsg = expired_nsg(root).await;
+
+        assert_eq!(
+            nsg.request_authorized(String::new(), None).await,
+            Ok("data".to_string())
+        );
+        assert_eq!(nsg.session(), Some("fresh-2".to_string()));
+        assert_eq!(logins.load(Ordering::SeqCst), 2);
+    });
+}
+
+#[test]
+fn from_session_expires() {
+    let (root, logins) = mock_portal();
+
+    let nsg = NsgBuilder::new(root, "client".to_string(), "x.y".to_string())
+        .build_with_session("expired".to_string())
+        .unwrap();
+
+    assert_eq!(
+        tokio_test::block_on(nsg.request_authorized(String::new(), None)),
+        Err(NsgError::SessionExpired)
+    );
+    assert_eq!(logins.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn concurrent_requests_share_relogin() {
+    let (root, logins) = mock_portal();
+
+    tokio_test::block_on(async {
+        let nsg = expired_nsg(root).await;
+        let clone = nsg.clone();
+
+        let (first, second) = futures::join!(
+            nsg.request_authorized(String::new(), None),
+            clone.request_authorized(String::new(), None)
+        );
+
+        assert_eq!(first, Ok("data".to_string()));
+        assert_eq!(second, Ok("data".to_string()));
+        assert_eq!(logins.load(Ordering::SeqCst), 2);
+    });
+}
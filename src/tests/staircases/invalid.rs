@@ -0,0 +1,45 @@
+use std::fs;
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
+use crate::staircases::Staircases;
+
+fn staircases() -> Staircases {
+    let staircases_html = fs::read_to_string("src/tests/assets/staircases/invalid/staircases.html")
+        .expect("Should have been able to read the file");
+    let comments_html = fs::read_to_string("src/tests/assets/staircases/invalid/comments.html")
+        .expect("Should have been able to read the file");
+
+    Staircases::from(13879, &staircases_html, &comments_html)
+}
+
+#[test]
+fn staircase() {
+    let staircases = staircases();
+    let staircase = staircases.staircases.first().unwrap();
+
+    assert_eq!(staircase.number, Some(Err(SerializableIntErrorKind::InvalidDigit)));
+    assert_eq!(
+        staircase.apartments_to,
+        Some(Err(SerializableIntErrorKind::InvalidDigit))
+    );
+    assert!(!staircase.contains_apartment(1));
+}
+
+#[test]
+fn comment() {
+    let staircases = staircases();
+
+    assert_eq!(
+        staircases.comments.first().unwrap().created,
+        Some(Err(SerializableParseErrorKind::Invalid))
+    );
+}
+
+#[test]
+fn empty() {
+    let staircases = Staircases::from(13879, "", "");
+
+    assert_eq!(staircases.staircases, vec![]);
+    assert_eq!(staircases.comments, vec![]);
+}
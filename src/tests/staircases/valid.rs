@@ -0,0 +1,78 @@
+use std::fs;
+
+use chrono::DateTime;
+
+use crate::staircases::staircase::Staircase;
+use crate::staircases::Staircases;
+
+fn staircases() -> Staircases {
+    let staircases_html = fs::read_to_string("src/tests/assets/staircases/valid/staircases.html")
+        .expect("Should have been able to read the file");
+    let comments_html = fs::read_to_string("src/tests/assets/staircases/valid/comments.html")
+        .expect("Should have been able to read the file");
+
+    Staircases::from(13879, &staircases_html, &comments_html)
+}
+
+#[test]
+fn staircases_list() {
+    let staircases = staircases();
+
+    assert_eq!(staircases.address_id, 13879);
+    assert_eq!(staircases.address, None);
+    assert_eq!(staircases.staircases, vec![
+        Staircase {
+            id:              Some(Ok(20411)),
+            number:          Some(Ok(1)),
+            lkd:             Some("ЛКД-1 (9 эт.)".to_string()),
+            ports:           Some("24".to_string()),
+            vbss:            Some("ВБСС-1".to_string()),
+            tkd:             Some("ТКД-1".to_string()),
+            apartments_from: Some(Ok(1)),
+            apartments_to:   Some(Ok(36)),
+        },
+        Staircase {
+            id:              Some(Ok(20412)),
+            number:          Some(Ok(2)),
+            lkd:             None,
+            ports:           Some("16".to_string()),
+            vbss:            None,
+            tkd:             Some("ТКД-1".to_string()),
+            apartments_from: Some(Ok(37)),
+            apartments_to:   Some(Ok(72)),
+        },
+    ]);
+    assert!(staircases.staircases[0].contains_apartment(36));
+    assert!(!staircases.staircases[0].contains_apartment(37));
+}
+
+#[test]
+fn comments() {
+    let staircases = staircases();
+
+    assert_eq!(staircases.comments.len(), 2);
+
+    let comment = staircases.comments.first().unwrap();
+
+    assert_eq!(comment.id, Some(Ok(5120)));
+    assert_eq!(comment.staircase, Some(Ok(1)));
+    assert_eq!(
+        comment.text,
+        Some("Код домофона 36К1234 Ключ от щитовой у консьержа".to_string())
+    );
+    assert_eq!(comment.author, Some("████ █████ █████████".to_string()));
+    assert_eq!(
+        comment.created,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-04-02 12:45:10+03:00").unwrap()))
+    );
+    assert_eq!(staircases.comments[1].staircase, None);
+}
+
+#[test]
+fn comments_for() {
+    let staircases = staircases();
+
+    assert_eq!(staircases.comments_for(1).len(), 2);
+    assert_eq!(staircases.comments_for(2).len(), 1);
+    assert_eq!(staircases.comments_for(2)[0].id, Some(Ok(5187)));
+}
@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::error::NsgError;
+use crate::table_query::Table;
+
+#[test]
+fn with_headers() {
+    let html =
+        fs::read_to_string("src/tests/assets/incidents/valid/1.html").expect("Should have been able to read the file");
+
+    let table = Table::from(&html);
+
+    assert_eq!(table.headers, vec![
+        "№",
+        "Тип",
+        "Подтип",
+        "Статус",
+        "Описание",
+        "Создан",
+        "Обновлен",
+        "Автор"
+    ]);
+    assert_eq!(table.rows.len(), 2);
+    assert_eq!(table.rows[0].id, Some("31874".to_string()));
+    assert_eq!(table.rows[0].get("Подтип"), Some("Нет доступа в подъезд"));
+    assert_eq!(table.rows[1].get("Подтип"), Some(""));
+    assert_eq!(table.rows[1].get("???"), None);
+}
+
+#[test]
+fn without_headers() {
+    let html = fs::read_to_string("src/tests/assets/basic_search/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let table = Table::from(&html);
+
+    assert!(table.headers.is_empty());
+    assert_eq!(table.rows.len(), 1);
+    assert_eq!(table.rows[0].id, Some("950599".to_string()));
+    assert_eq!(table.rows[0].get("0"), Some("950599"));
+    assert_eq!(table.rows[0].get("12"), Some("Не активировано"));
+}
+
+#[test]
+fn class_as_column() {
+    let table = Table::from(
+        "<tr class=\"tablerrow\" rowid=\"7\"><td class=\"attention\">1</td><td \
+         class=\"updaterfio\"></td><td>x</td></tr>",
+    );
+
+    assert_eq!(
+        table.rows[0].cells,
+        BTreeMap::from([
+            ("attention".to_string(), "1".to_string()),
+            ("updaterfio".to_string(), "".to_string()),
+            ("2".to_string(), "x".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn plain_rows() {
+    let table = Table::from("<tr><th>A</th></tr><tr><td>1</td></tr><tr><td>2</td></tr>");
+
+    assert_eq!(table.rows.len(), 2);
+    assert_eq!(table.rows[1].get("A"), Some("2"));
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Incident {
+    #[serde(rename = "№")]
+    id:      String,
+    #[serde(rename = "Подтип")]
+    subtype: String,
+}
+
+#[test]
+fn deserialize_rows() {
+    let html =
+        fs::read_to_string("src/tests/assets/incidents/valid/1.html").expect("Should have been able to read the file");
+
+    assert_eq!(
+        Table::from(&html).deserialize_rows::<Incident>(),
+        Ok(vec![
+            Incident {
+                id:      "31874".to_string(),
+                subtype: "Нет доступа в подъезд".to_string(),
+            },
+            Incident {
+                id:      "31901".to_string(),
+                subtype: "".to_string(),
+            },
+        ])
+    );
+}
+
+#[test]
+fn deserialize_rows_missing_column() {
+    let table = Table::from("<tr><th>№</th></tr><tr><td>1</td></tr>");
+
+    assert!(matches!(
+        table.deserialize_rows::<Incident>(),
+        Err(NsgError::DeserializeError(_))
+    ));
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Instance {
+    instance: u32,
+    status:   String,
+}
+
+#[test]
+fn deserialize_json() {
+    assert_eq!(
+        Table::deserialize_json::<Instance>(r#"[{"instance":1,"status":"a"},{"instance":2,"status":"b"}]"#),
+        Ok(vec![
+            Instance {
+                instance: 1,
+                status:   "a".to_string(),
+            },
+            Instance {
+                instance: 2,
+                status:   "b".to_string(),
+            },
+        ])
+    );
+    assert!(matches!(
+        Table::deserialize_json::<Instance>("<form id=\"logoform\"></form>"),
+        Err(NsgError::DeserializeError(_))
+    ));
+}
@@ -0,0 +1,20 @@
+use std::fs;
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::user::User;
+
+#[test]
+fn invalid_id() {
+    let json =
+        fs::read_to_string("src/tests/assets/user/invalid/id.json").expect("Should have been able to read the file");
+
+    let user = User::from(&json);
+
+    assert_eq!(user.id, Some(Err(SerializableIntErrorKind::InvalidDigit)));
+    assert!(user.into_guaranteed().is_none());
+}
+
+#[test]
+fn not_json() {
+    assert_eq!(User::from("<form id=\"logoform\"></form>"), User::default());
+}
@@ -0,0 +1,3 @@
+mod invalid;
+mod valid_1;
+mod valid_2;
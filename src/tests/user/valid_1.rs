@@ -0,0 +1,33 @@
+use std::fs;
+
+use crate::user::User;
+
+#[test]
+fn should_guarantee() {
+    let json =
+        fs::read_to_string("src/tests/assets/user/valid/1.json").expect("Should have been able to read the file");
+
+    let user = User::from(&json);
+
+    assert!(user.into_guaranteed().is_some());
+}
+
+#[test]
+fn fields() {
+    let json =
+        fs::read_to_string("src/tests/assets/user/valid/1.json").expect("Should have been able to read the file");
+
+    let user = User::from(&json);
+
+    assert_eq!(user.id, Some(Ok(1478)));
+    assert_eq!(user.full_name, Some("████ █████ █████████".to_string()));
+    assert_eq!(user.role, Some("Монтажник (все)".to_string()));
+    assert_eq!(user.city, Some("Запоріжжя".to_string()));
+    assert_eq!(user.permissions, vec![
+        "viewRequest".to_string(),
+        "addRequestComment".to_string(),
+        "createIncident".to_string(),
+        "workschedule1".to_string(),
+    ]);
+    assert!(user.is_installer());
+}
@@ -0,0 +1,22 @@
+use std::fs;
+
+use crate::user::User;
+
+#[test]
+fn fields() {
+    let json =
+        fs::read_to_string("src/tests/assets/user/valid/2.json").expect("Should have been able to read the file");
+
+    let user = User::from(&json);
+
+    assert_eq!(user.id, Some(Ok(1088)));
+    assert_eq!(user.full_name, Some("████████ ██████ ███████████".to_string()));
+    assert_eq!(user.role, Some("Диспетчер".to_string()));
+    assert_eq!(user.city, None);
+    assert_eq!(user.permissions, vec![
+        "viewRequest".to_string(),
+        "rollbackRequest".to_string(),
+        "confirmSBMSChange".to_string(),
+    ]);
+    assert!(!user.is_installer());
+}
@@ -2,6 +2,8 @@ use std::fs;
 
 use paste::paste;
 
+use crate::data::aggregation_point::AggregationPointError;
+use crate::data::technology::TechnologyError;
 use crate::serializable_int_error_kind::SerializableIntErrorKind;
 use crate::serializable_parse_error_kind::SerializableParseErrorKind;
 use crate::tests::test;
@@ -12,7 +14,25 @@ test! {
     internal_order_id: { Some(Err(SerializableIntErrorKind::InvalidDigit)), invalid_u8; },
     order_id: { Some(Err(SerializableIntErrorKind::InvalidDigit)), invalid_u32; },
     service: { None, empty_service; },
+    address_id: { Some(Err(SerializableIntErrorKind::InvalidDigit)), invalid_u32; },
+    related_order_id: { Some(Err(SerializableIntErrorKind::InvalidDigit)), invalid_u32; },
     assigned_for: { Some(Err(SerializableParseErrorKind::Invalid)), invalid_naive_date; },
+    technology: { Some(Err(TechnologyError::InvalidTechnology("FTTX".to_string()))), unknown_technology; },
+    aggregation_point: {
+        Some(Err(AggregationPointError::NoSeparator("Запоріжжя".to_string()))),
+        no_technology_prefix;
+    },
+}
+
+#[test]
+fn deadline_invalid_datetime() {
+    let html = fs::read_to_string("src/tests/assets/view_request/invalid/deadline.html")
+        .expect("Should have been able to read the file");
+
+    assert_eq!(
+        ViewRequest::from(&html).deadlines.deadline,
+        Some(Err(SerializableParseErrorKind::TooShort))
+    );
 }
 
 #[test]
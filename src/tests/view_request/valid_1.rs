@@ -3,10 +3,16 @@ use std::fs;
 use chrono::{DateTime, NaiveDate, NaiveTime};
 
 use crate::data::address::Address;
+use crate::data::aggregation_point::AggregationPoint;
+use crate::data::connection_point::ConnectionPoint;
+use crate::data::customer_status::CustomerStatus;
+use crate::data::equipment::Equipment;
 use crate::data::full_comment::FullComment;
 use crate::data::internal_status::InternalStatus;
-use crate::data::order_type::OrderType;
+use crate::data::order_type::{OrderCategory, OrderSubtype, OrderType};
+use crate::data::service::Service;
 use crate::data::status::Status;
+use crate::data::technology::Technology;
 use crate::data::time_constrains::TimeConstrains;
 use crate::view_request::ViewRequest;
 
@@ -29,11 +35,18 @@ fn fields() {
 
     assert_eq!(view_request.order_id, Some(Ok(13351186)));
     assert_eq!(view_request.internal_order_id, Some(Ok(950207)));
-    assert_eq!(view_request.order_type, Some(Ok(OrderType::NetGigabit)));
+    assert_eq!(
+        view_request.order_type,
+        Some(Ok(OrderType {
+            category: Some(OrderCategory::Service),
+            subtype:  OrderSubtype::Gigabit,
+        }))
+    );
     assert_eq!(
         view_request.creation_date,
         Some(Ok(DateTime::parse_from_rfc3339("2024-05-13 16:16:00+03:00").unwrap()))
     );
+    assert_eq!(view_request.address_id, Some(Ok(13879)));
     assert_eq!(view_request.internal_status, Some(Ok(InternalStatus::Completed)));
     assert_eq!(
         view_request.address,
@@ -133,3 +146,136 @@ fn comments() {
         })
     ])
 }
+
+#[test]
+fn related_order_id() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    assert_eq!(ViewRequest::from(&html).related_order_id, None);
+
+    let html = html.replace(
+        r#"<tr><th align="left">Наряд:</th>"#,
+        r#"<tr><th align="left">Номер связанной заявки:</th><td>948112</td></tr><tr><th align="left">Наряд:</th>"#,
+    );
+    let view_request = ViewRequest::from(&html);
+
+    assert_eq!(view_request.related_order_id, Some(Ok(948112)));
+    assert_eq!(view_request.into_guaranteed().unwrap().related_order_id, Some(948112));
+}
+
+#[test]
+fn network() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let view_request = ViewRequest::from(&html);
+
+    assert_eq!(view_request.technology, Some(Ok(Technology::FTTB)));
+    assert_eq!(view_request.tkd, None);
+    assert_eq!(
+        view_request.aggregation_point,
+        Some(Ok(AggregationPoint {
+            technology: Technology::FTTB,
+            name:       "Запоріжжя".to_string(),
+        }))
+    );
+    assert_eq!(view_request.connection_points, vec![Ok(ConnectionPoint {
+        name:        "Gigabit".to_string(),
+        buyout:      1,
+        rent:        0,
+        safekeeping: 0,
+        packages:    None,
+    })]);
+    assert_eq!(view_request.services, vec![Ok(Service {
+        id:     1,
+        name:   "Подключение к сети FTTB".to_string(),
+        source: Some("Подтип Гігабіт".to_string()),
+    })]);
+    assert_eq!(view_request.equipment.len(), 4);
+    assert_eq!(
+        view_request.equipment[0],
+        Ok(Equipment {
+            item:     "Кабель UTP".to_string(),
+            quantity: 40,
+            spec:     Some("4х2х0,51 БМ".to_string()),
+            action:   "Выдать(абонент)".to_string(),
+            owner:    "-Абонент-".to_string(),
+        })
+    );
+    assert_eq!(
+        view_request.equipment[1],
+        Ok(Equipment {
+            item:     "Коннектор RG-45".to_string(),
+            quantity: 9,
+            spec:     None,
+            action:   "Выдать(абонент)".to_string(),
+            owner:    "-Абонент-".to_string(),
+        })
+    );
+    assert_eq!(view_request.connection_method, Some("ВДСС".to_string()));
+}
+
+#[test]
+fn deadlines() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let view_request = ViewRequest::from(&html);
+
+    assert!(!view_request.deadlines.urgent);
+    assert_eq!(
+        view_request.deadlines.deadline,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-15 16:16:59+03:00").unwrap()))
+    );
+    assert_eq!(view_request.deadlines.planned_activation, None);
+    assert_eq!(view_request.deadlines.desired_time, None);
+    assert_eq!(
+        view_request.scheduled_at,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-16 11:00:00+03:00").unwrap()))
+    );
+    assert_eq!(
+        view_request.deadlines.callback_date,
+        Some(Ok(NaiveDate::from_ymd_opt(2024, 5, 16).unwrap()))
+    );
+}
+
+#[test]
+fn customer_status_without_change() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/1.html")
+        .expect("Should have been able to read the file");
+
+    let view_request = ViewRequest::from(&html);
+
+    assert_eq!(
+        view_request.customer_status,
+        Some(Ok(CustomerStatus {
+            status:          Status::Completed,
+            internal_status: None,
+            user:            None,
+            datetime:        None,
+        }))
+    );
+    assert_eq!(view_request.status_change, None);
+}
+
+#[test]
+fn customer_status_with_transition() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/1.html")
+        .expect("Should have been able to read the file")
+        .replace(
+            "<th align=\"left\">Статус у заказчика:</th><td>Выполнена</td>",
+            "<th align=\"left\">Статус у заказчика:</th><td>Отклонена. Переведено в Не активировано (Іван Іванович \
+             Іванов,2024-05-16 16:42:20)</td>",
+        );
+
+    assert_eq!(
+        ViewRequest::from(&html).customer_status,
+        Some(Ok(CustomerStatus {
+            status:          Status::Declined,
+            internal_status: Some(InternalStatus::NotActivated),
+            user:            Some("Іван Іванович Іванов".to_string()),
+            datetime:        Some(DateTime::parse_from_rfc3339("2024-05-16 16:42:20+03:00").unwrap()),
+        }))
+    );
+}
@@ -3,9 +3,10 @@ use std::fs;
 use chrono::{DateTime, NaiveDate, NaiveTime};
 
 use crate::data::address::Address;
+use crate::data::customer_status::CustomerStatus;
 use crate::data::full_comment::FullComment;
 use crate::data::internal_status::InternalStatus;
-use crate::data::order_type::OrderType;
+use crate::data::order_type::{OrderCategory, OrderSubtype, OrderType};
 use crate::data::status::Status;
 use crate::data::time_constrains::TimeConstrains;
 use crate::view_request::ViewRequest;
@@ -29,11 +30,18 @@ fn fields() {
 
     assert_eq!(view_request.order_id, Some(Ok(13354143)));
     assert_eq!(view_request.internal_order_id, Some(Ok(950599)));
-    assert_eq!(view_request.order_type, Some(Ok(OrderType::NetBroken)));
+    assert_eq!(
+        view_request.order_type,
+        Some(Ok(OrderType {
+            category: Some(OrderCategory::Service),
+            subtype:  OrderSubtype::ServiceComplaints,
+        }))
+    );
     assert_eq!(
         view_request.creation_date,
         Some(Ok(DateTime::parse_from_rfc3339("2024-05-15 10:49:41+03:00").unwrap()))
     );
+    assert_eq!(view_request.address_id, Some(Ok(13889)));
     assert_eq!(view_request.internal_status, Some(Ok(InternalStatus::NotActivated)));
     assert_eq!(
         view_request.address,
@@ -104,3 +112,30 @@ fn comments() {
         })
     ])
 }
+
+#[test]
+fn customer_status() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/2.html")
+        .expect("Should have been able to read the file");
+
+    let view_request = ViewRequest::from(&html);
+
+    assert_eq!(
+        view_request.customer_status,
+        Some(Ok(CustomerStatus {
+            status:          Status::Deleted,
+            internal_status: None,
+            user:            None,
+            datetime:        None,
+        }))
+    );
+    assert_eq!(
+        view_request.status_change,
+        Some(Ok(CustomerStatus {
+            status:          Status::Declined,
+            internal_status: Some(InternalStatus::NotActivated),
+            user:            Some("████████ ██████ ███████████".to_string()),
+            datetime:        Some(DateTime::parse_from_rfc3339("2024-05-16 16:42:20+03:00").unwrap()),
+        }))
+    );
+}
@@ -5,7 +5,7 @@ use chrono::{DateTime, NaiveDate, NaiveTime};
 use crate::data::address::Address;
 use crate::data::full_comment::FullComment;
 use crate::data::internal_status::InternalStatus;
-use crate::data::order_type::OrderType;
+use crate::data::order_type::{OrderCategory, OrderSubtype, OrderType};
 use crate::data::status::Status;
 use crate::data::time_constrains::TimeConstrains;
 use crate::view_request::ViewRequest;
@@ -29,11 +29,18 @@ fn fields() {
 
     assert_eq!(view_request.order_id, Some(Ok(13355298)));
     assert_eq!(view_request.internal_order_id, Some(Ok(950767)));
-    assert_eq!(view_request.order_type, Some(Ok(OrderType::OthersMaster)));
+    assert_eq!(
+        view_request.order_type,
+        Some(Ok(OrderType {
+            category: Some(OrderCategory::Service),
+            subtype:  OrderSubtype::Master(None),
+        }))
+    );
     assert_eq!(
         view_request.creation_date,
         Some(Ok(DateTime::parse_from_rfc3339("2024-05-15 18:33:00+03:00").unwrap()))
     );
+    assert_eq!(view_request.address_id, Some(Ok(9402)));
     assert_eq!(view_request.internal_status, Some(Ok(InternalStatus::Rejected)));
     assert_eq!(
         view_request.address,
@@ -5,8 +5,9 @@ use chrono::{DateTime, NaiveDate, NaiveTime};
 use crate::data::address::Address;
 use crate::data::full_comment::FullComment;
 use crate::data::internal_status::InternalStatus;
-use crate::data::order_type::OrderType;
+use crate::data::order_type::{OrderCategory, OrderSubtype, OrderType};
 use crate::data::status::Status;
+use crate::data::technology::Technology;
 use crate::data::time_constrains::TimeConstrains;
 use crate::view_request::ViewRequest;
 
@@ -29,11 +30,18 @@ fn fields() {
 
     assert_eq!(view_request.order_id, Some(Ok(13413282)));
     assert_eq!(view_request.internal_order_id, Some(Ok(958790)));
-    assert_eq!(view_request.order_type, Some(Ok(OrderType::NetNewUnknown)));
+    assert_eq!(
+        view_request.order_type,
+        Some(Ok(OrderType {
+            category: Some(OrderCategory::Active),
+            subtype:  OrderSubtype::NewConnection,
+        }))
+    );
     assert_eq!(
         view_request.creation_date,
         Some(Ok(DateTime::parse_from_rfc3339("2024-06-15 15:39:12+03:00").unwrap()))
     );
+    assert_eq!(view_request.address_id, Some(Ok(13874)));
     assert_eq!(view_request.internal_status, Some(Ok(InternalStatus::Assigned)));
     assert_eq!(
         view_request.address,
@@ -98,3 +106,62 @@ fn comments() {
         })
     ])
 }
+
+#[test]
+fn network() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/4.html")
+        .expect("Should have been able to read the file");
+
+    let view_request = ViewRequest::from(&html);
+
+    assert_eq!(view_request.technology, Some(Ok(Technology::FTTB)));
+    assert_eq!(view_request.tkd, None);
+    assert_eq!(
+        view_request
+            .connection_points
+            .iter()
+            .map(|connection_point| connection_point.clone().unwrap().name)
+            .collect::<Vec<_>>(),
+        vec![
+            "Роутер",
+            "Роутер PRO",
+            "СИМ-карта",
+            "ТВ-тюнер",
+            "Роутер ПЛЮС",
+            "Gigabit"
+        ]
+    );
+    assert_eq!(view_request.connection_points[5].clone().unwrap().total(), 2);
+    assert_eq!(view_request.equipment, vec![]);
+    assert_eq!(view_request.connection_method, None);
+}
+
+#[test]
+fn deadlines() {
+    let html = fs::read_to_string("src/tests/assets/view_request/valid/4.html")
+        .expect("Should have been able to read the file");
+
+    let view_request = ViewRequest::from(&html);
+
+    assert!(!view_request.deadlines.urgent);
+    assert_eq!(
+        view_request.deadlines.deadline,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-07-11 23:59:59+03:00").unwrap()))
+    );
+    assert_eq!(
+        view_request.deadlines.planned_activation,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-07-11 00:00:00+03:00").unwrap()))
+    );
+    assert_eq!(
+        view_request.deadlines.desired_time,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-06-17 13:00:00+03:00").unwrap()))
+    );
+    assert_eq!(
+        view_request.scheduled_at,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-06-17 13:00:00+03:00").unwrap()))
+    );
+    assert_eq!(
+        view_request.deadlines.callback_date,
+        Some(Ok(NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()))
+    );
+}
@@ -0,0 +1,45 @@
+use crate::builder::NsgBuilder;
+use crate::error::NsgError;
+use crate::work_schedule::detailed::DetailOptions;
+use crate::work_schedule::order::Order;
+
+#[test]
+fn no_internal_order_id() {
+    let nsg = NsgBuilder::new(
+        "https://net-stroy.itnet.lviv.ua".to_string(),
+        "client".to_string(),
+        "x.y".to_string(),
+    )
+    .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+    .unwrap();
+
+    let detailed_order = tokio_test::block_on(nsg.detail_order(Order::default(), &DetailOptions {
+        view_request:  true,
+        brief_request: true,
+        concurrency:   1,
+    }));
+
+    assert_eq!(detailed_order.order, Order::default());
+    assert_eq!(detailed_order.view_request, Some(Err(NsgError::NoInternalOrderId)));
+    assert_eq!(detailed_order.brief_request, Some(Err(NsgError::NoInternalOrderId)));
+}
+
+#[test]
+fn not_requested() {
+    let nsg = NsgBuilder::new(
+        "https://net-stroy.itnet.lviv.ua".to_string(),
+        "client".to_string(),
+        "x.y".to_string(),
+    )
+    .build_with_session("75tlg96e5id8c3r0k0d6c8j4s0".to_string())
+    .unwrap();
+
+    let detailed_order = tokio_test::block_on(nsg.detail_order(Order::default(), &DetailOptions {
+        view_request:  false,
+        brief_request: false,
+        concurrency:   1,
+    }));
+
+    assert_eq!(detailed_order.view_request, None);
+    assert_eq!(detailed_order.brief_request, None);
+}
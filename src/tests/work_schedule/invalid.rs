@@ -3,6 +3,7 @@ use std::fs;
 use paste::paste;
 
 use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
 use crate::tests::test;
 use crate::work_schedule::WorkSchedule;
 
@@ -37,4 +38,14 @@ test! {
         None, no_element, first;
         None, no_text, get, 1;
     },
+    address_id: {
+        Some(Err(SerializableIntErrorKind::InvalidDigit)), invalid_u32, first;
+    },
+    last_sbms_update: {
+        Some(Err(SerializableParseErrorKind::Invalid)), invalid_datetime, first;
+    },
+    sbms_duration: {
+        Some(Err(SerializableIntErrorKind::Empty)), no_seconds, first;
+        Some(Err(SerializableIntErrorKind::PosOverflow)), overflow, get, 1;
+    },
 }
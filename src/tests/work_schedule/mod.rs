@@ -1,3 +1,4 @@
+mod detailed;
 mod invalid;
 mod selector;
 mod valid_1;
@@ -1,11 +1,11 @@
 use std::fs;
 
-use chrono::NaiveTime;
+use chrono::{DateTime, Duration, NaiveTime};
 
 use crate::data::address::Address;
 use crate::data::internal_status::InternalStatus;
 use crate::data::mdu::MDU;
-use crate::data::order_type::OrderType;
+use crate::data::order_type::{OrderCategory, OrderSubtype, OrderType};
 use crate::data::status::Status;
 use crate::data::time_constrains::TimeConstrains;
 use crate::work_schedule::WorkSchedule;
@@ -54,6 +54,7 @@ fn order_13367829() {
             apartment: "██".to_string(),
         }))
     );
+    assert_eq!(order.address_id, Some(Ok(14605)));
     assert_eq!(
         order.mdu,
         Some(Ok(MDU {
@@ -62,9 +63,24 @@ fn order_13367829() {
         }))
     );
     assert_eq!(order.status, Some(Ok(Status::Completed)));
-    assert_eq!(order.order_type, Some(Ok(OrderType::NetBroken)));
+    assert_eq!(
+        order.order_type,
+        Some(Ok(OrderType {
+            category: Some(OrderCategory::Service),
+            subtype:  OrderSubtype::ServiceComplaints,
+        }))
+    );
     assert_eq!(order.client, Some("██████████ █████ █████████████".to_string()));
     assert_eq!(order.internal_status, Some(Ok(InternalStatus::Completed)));
+    assert_eq!(
+        order.last_sbms_update,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-25 15:14:35+03:00").unwrap()))
+    );
+    assert_eq!(
+        order.sbms_duration,
+        Some(Ok(Duration::hours(65) + Duration::minutes(53) + Duration::seconds(31)))
+    );
+    assert_eq!(order.sbms_critical, Some(false));
 }
 
 #[test]
@@ -96,6 +112,7 @@ fn order_13370195() {
             apartment: "█".to_string(),
         }))
     );
+    assert_eq!(order.address_id, Some(Ok(13884)));
     assert_eq!(
         order.mdu,
         Some(Ok(MDU {
@@ -104,7 +121,13 @@ fn order_13370195() {
         }))
     );
     assert_eq!(order.status, Some(Ok(Status::Completed)));
-    assert_eq!(order.order_type, Some(Ok(OrderType::NetNewActive)));
+    assert_eq!(
+        order.order_type,
+        Some(Ok(OrderType {
+            category: Some(OrderCategory::Active),
+            subtype:  OrderSubtype::NewConnection,
+        }))
+    );
     assert_eq!(order.client, Some("████████ ███████ ████████████".to_string()));
     assert_eq!(order.internal_status, Some(Ok(InternalStatus::Completed)));
 }
@@ -138,6 +161,7 @@ fn order_13372183() {
             apartment: "█".to_string(),
         }))
     );
+    assert_eq!(order.address_id, Some(Ok(23287)));
     assert_eq!(
         order.mdu,
         Some(Ok(MDU {
@@ -146,7 +170,22 @@ fn order_13372183() {
         }))
     );
     assert_eq!(order.status, Some(Ok(Status::Completed)));
-    assert_eq!(order.order_type, Some(Ok(OrderType::NetNewPassive)));
+    assert_eq!(
+        order.order_type,
+        Some(Ok(OrderType {
+            category: Some(OrderCategory::Passive),
+            subtype:  OrderSubtype::NewConnection,
+        }))
+    );
     assert_eq!(order.client, Some("█████ ███████ ██████████".to_string()));
     assert_eq!(order.internal_status, Some(Ok(InternalStatus::Completed)));
+    assert_eq!(
+        order.last_sbms_update,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-25 15:14:49+03:00").unwrap()))
+    );
+    assert_eq!(
+        order.sbms_duration,
+        Some(Ok(Duration::hours(1) + Duration::minutes(54) + Duration::seconds(55)))
+    );
+    assert_eq!(order.sbms_critical, Some(true));
 }
@@ -1,11 +1,11 @@
 use std::fs;
 
-use chrono::NaiveTime;
+use chrono::{DateTime, Duration, NaiveTime};
 
 use crate::data::address::Address;
 use crate::data::internal_status::InternalStatus;
 use crate::data::mdu::MDU;
-use crate::data::order_type::OrderType;
+use crate::data::order_type::{OrderCategory, OrderSubtype, OrderType};
 use crate::data::status::Status;
 use crate::data::time_constrains::TimeConstrains;
 use crate::work_schedule::WorkSchedule;
@@ -51,6 +51,7 @@ fn order_13372027() {
             apartment: "██".to_string(),
         }))
     );
+    assert_eq!(order.address_id, Some(Ok(13900)));
     assert_eq!(
         order.mdu,
         Some(Ok(MDU {
@@ -59,9 +60,24 @@ fn order_13372027() {
         }))
     );
     assert_eq!(order.status, Some(Ok(Status::InWork)));
-    assert_eq!(order.order_type, Some(Ok(OrderType::NetNewPassive)));
+    assert_eq!(
+        order.order_type,
+        Some(Ok(OrderType {
+            category: Some(OrderCategory::Passive),
+            subtype:  OrderSubtype::NewConnection,
+        }))
+    );
     assert_eq!(order.client, Some("█████████ ███████ ██████████".to_string()));
     assert_eq!(order.internal_status, Some(Ok(InternalStatus::Assigned)));
+    assert_eq!(
+        order.last_sbms_update,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-25 11:27:45+03:00").unwrap()))
+    );
+    assert_eq!(
+        order.sbms_duration,
+        Some(Ok(Duration::hours(2) + Duration::minutes(6) + Duration::seconds(6)))
+    );
+    assert_eq!(order.sbms_critical, Some(false));
 }
 
 #[test]
@@ -96,6 +112,7 @@ fn order_13373090() {
             apartment: "██".to_string(),
         }))
     );
+    assert_eq!(order.address_id, Some(Ok(13892)));
     assert_eq!(
         order.mdu,
         Some(Ok(MDU {
@@ -104,7 +121,22 @@ fn order_13373090() {
         }))
     );
     assert_eq!(order.status, Some(Ok(Status::Assigned)));
-    assert_eq!(order.order_type, Some(Ok(OrderType::NetBroken)));
+    assert_eq!(
+        order.order_type,
+        Some(Ok(OrderType {
+            category: Some(OrderCategory::Service),
+            subtype:  OrderSubtype::ServiceComplaints,
+        }))
+    );
     assert_eq!(order.client, Some("█████████ ███████ ████████".to_string()));
     assert_eq!(order.internal_status, Some(Ok(InternalStatus::Assigned)));
+    assert_eq!(
+        order.last_sbms_update,
+        Some(Ok(DateTime::parse_from_rfc3339("2024-05-25 19:27:51+03:00").unwrap()))
+    );
+    assert_eq!(
+        order.sbms_duration,
+        Some(Ok(Duration::hours(0) + Duration::minutes(11) + Duration::seconds(41)))
+    );
+    assert_eq!(order.sbms_critical, Some(true));
 }
@@ -0,0 +1,17 @@
+use serde_json::Value;
+
+pub trait JsonValue {
+    fn get_string(&self, key: &str) -> Option<String>;
+}
+
+impl JsonValue for Value {
+    /// Portal isn't consistent with types, so numbers can be sent as strings
+    /// and vice versa. Empty strings are `None`
+    fn get_string(&self, key: &str) -> Option<String> {
+        match self.get(key)? {
+            Value::String(string) if !string.is_empty() => Some(string.clone()),
+            Value::Number(number) => Some(number.to_string()),
+            _ => None,
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub(crate) mod is_it;
+pub(crate) mod json_value;
 pub(crate) mod prev_element_ref;
@@ -0,0 +1,30 @@
+//! Guaranteed user
+
+use serde::{Deserialize, Serialize};
+
+use super::User;
+
+/// Hence [`User`] will not fail hard, it's not necessary valid. You can
+/// guarantee validness of user with [`User::into_guaranteed`]. For detailed
+/// information about field refer to it's documentation or [`User`]'s
+/// documentation
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct GuaranteedUser {
+    pub id:          u32,
+    pub full_name:   String,
+    pub role:        String,
+    pub city:        Option<String>,
+    pub permissions: Vec<String>,
+}
+
+impl User {
+    pub fn into_guaranteed(self) -> Option<GuaranteedUser> {
+        Some(GuaranteedUser {
+            id:          self.id?.ok()?,
+            full_name:   self.full_name?,
+            role:        self.role?,
+            city:        self.city,
+            permissions: self.permissions,
+        })
+    }
+}
@@ -0,0 +1,87 @@
+//! User parser
+//!
+//! Portal responds to `actiondata=core_getUser` with JSON describing the user
+//! of the current session. Only the keys `id`, `fio`, `role`, `city` and
+//! `rights` are read
+//!
+//! **Experimental**: the example JSONs aren't captured from Portal, so the
+//! keys may not match the real response
+//!
+//! ## Example usage
+//! You can find example JSONs in `src/tests/assets/user/valid`
+//!
+//! ```
+//! use nsg::user::User;
+//!
+//! let json = include_str!("../tests/assets/user/valid/1.json");
+//! let user = User::from(&json);
+//!
+//! println!("User: {:#?}", user);
+//! ```
+
+pub mod guaranteed;
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::traits::json_value::JsonValue;
+
+/// Role this library is written for. See [`User::is_installer`]
+pub const INSTALLER_ROLE: &str = "Монтажник (все)";
+
+/// Parsed user of the current session. Note that all fields will not fail hard
+/// allowing to work with partially valid user
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize, Default)]
+pub struct User {
+    pub id:          Option<Result<u32, SerializableIntErrorKind>>,
+    pub full_name:   Option<String>,
+    /// Role name, like "Монтажник (все)"
+    pub role:        Option<String>,
+    /// City (branch) the user belongs to
+    pub city:        Option<String>,
+    /// Names of actions user is permitted to perform
+    pub permissions: Vec<String>,
+}
+
+impl User {
+    /// Parse user from JSON
+    pub fn from(json: &str) -> User {
+        log::debug!(target: "nsg", "Processing JSON: {:?}", json);
+
+        let value = match serde_json::from_str::<Value>(json) {
+            Ok(value) => value,
+            Err(err) => {
+                log::warn!(target: "nsg", "User is not a valid JSON: {:?}", err);
+                return User::default();
+            },
+        };
+
+        User {
+            id:          value
+                .get_string("id")
+                .map(|id| u32::from_str(&id).map_err(|err| (*err.kind()).into())),
+            full_name:   value.get_string("fio").map(|full_name| full_name.trim().to_string()),
+            role:        value.get_string("role"),
+            city:        value.get_string("city"),
+            permissions: User::get_permissions(&value),
+        }
+    }
+
+    /// Whether user has the role of "Монтажник (все)". This library may not
+    /// work properly with other roles
+    pub fn is_installer(&self) -> bool { self.role.as_deref() == Some(INSTALLER_ROLE) }
+
+    /// Permissions are list of action names
+    fn get_permissions(value: &Value) -> Vec<String> {
+        match value.get("rights") {
+            Some(Value::Array(permissions)) => permissions
+                .iter()
+                .filter_map(|permission| permission.as_str().map(str::to_string))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -2,6 +2,7 @@ use itertools::Itertools;
 use scraper::{Html, Selector};
 
 use super::ViewRequest;
+use crate::data::customer_status::CustomerStatus;
 use crate::data::full_comment::FullComment;
 
 impl ViewRequest {
@@ -16,5 +17,13 @@ impl ViewRequest {
             .select(&comments_selector)
             .map(FullComment::from)
             .collect_vec();
+
+        self.status_change = self
+            .comments
+            .iter()
+            .flatten()
+            .filter_map(|full_comment| Some((full_comment.datetime, CustomerStatus::from_full_comment(full_comment)?)))
+            .max_by_key(|(datetime, _)| *datetime)
+            .map(|(_, customer_status)| customer_status);
     }
 }
@@ -5,10 +5,17 @@ use serde::{Deserialize, Serialize};
 
 use super::ViewRequest;
 use crate::data::address::Address;
+use crate::data::aggregation_point::AggregationPoint;
+use crate::data::connection_point::ConnectionPoint;
+use crate::data::customer_status::CustomerStatus;
+use crate::data::deadlines::GuaranteedDeadlines;
+use crate::data::equipment::Equipment;
 use crate::data::full_comment::FullComment;
 use crate::data::internal_status::InternalStatus;
 use crate::data::order_type::OrderType;
+use crate::data::service::Service;
 use crate::data::status::Status;
+use crate::data::technology::Technology;
 use crate::data::time_constrains::TimeConstrains;
 
 /// Hence [`ViewRequest`] will not fail hard, it's not necessary valid. You can
@@ -19,13 +26,22 @@ use crate::data::time_constrains::TimeConstrains;
 pub struct GuaranteedViewRequest {
     pub order_id:          u32,
     pub internal_order_id: u32,
+    pub related_order_id:  Option<u32>,
     pub order_type:        OrderType,
     pub creation_date:     DateTime<FixedOffset>,
     pub internal_status:   InternalStatus,
     pub address:           Address,
+    pub address_id:        u32,
     pub client:            String,
     pub service:           Option<String>,
+    pub services:          Vec<Service>,
     pub pa:                String,
+    pub technology:        Option<Technology>,
+    pub tkd:               Option<String>,
+    pub aggregation_point: Option<AggregationPoint>,
+    pub connection_points: Vec<ConnectionPoint>,
+    pub equipment:         Vec<Equipment>,
+    pub connection_method: Option<String>,
     pub seller:            Option<String>,
     pub time_constrains:   TimeConstrains,
     pub installers:        Vec<String>,
@@ -33,7 +49,11 @@ pub struct GuaranteedViewRequest {
     /// Will contain at least one element
     pub phones:            Vec<String>,
     pub assigned_for:      NaiveDate,
+    pub deadlines:         GuaranteedDeadlines,
+    pub scheduled_at:      Option<DateTime<FixedOffset>>,
     pub comments:          Vec<FullComment>,
+    pub customer_status:   CustomerStatus,
+    pub status_change:     Option<CustomerStatus>,
 }
 
 impl ViewRequest {
@@ -47,23 +67,66 @@ impl ViewRequest {
             comments.push(comment.ok()?);
         }
 
+        let mut connection_points = Vec::with_capacity(self.connection_points.len());
+        for connection_point in self.connection_points {
+            connection_points.push(connection_point.ok()?);
+        }
+
+        let mut services = Vec::with_capacity(self.services.len());
+        for service in self.services {
+            services.push(service.ok()?);
+        }
+
+        let mut equipment = Vec::with_capacity(self.equipment.len());
+        for item in self.equipment {
+            equipment.push(item.ok()?);
+        }
+
         Some(GuaranteedViewRequest {
             order_id: self.order_id?.ok()?,
             internal_order_id: self.internal_order_id?.ok()?,
+            related_order_id: match self.related_order_id {
+                Some(related_order_id) => Some(related_order_id.ok()?),
+                None => None,
+            },
             order_type: self.order_type?.ok()?,
             creation_date: self.creation_date?.ok()?,
             internal_status: self.internal_status?.ok()?,
             address: self.address?,
+            address_id: self.address_id?.ok()?,
             client: self.client?,
             service: self.service,
+            services,
             pa: self.pa?,
+            technology: match self.technology {
+                Some(technology) => Some(technology.ok()?),
+                None => None,
+            },
+            tkd: self.tkd,
+            aggregation_point: match self.aggregation_point {
+                Some(aggregation_point) => Some(aggregation_point.ok()?),
+                None => None,
+            },
+            connection_points,
+            equipment,
+            connection_method: self.connection_method,
             seller: self.seller,
             time_constrains: self.time_constrains?.ok()?,
             installers: self.installers,
             status: self.status?.ok()?,
             phones: self.phones,
             assigned_for: self.assigned_for?.ok()?,
+            deadlines: self.deadlines.into_guaranteed()?,
+            scheduled_at: match self.scheduled_at {
+                Some(scheduled_at) => Some(scheduled_at.ok()?),
+                None => None,
+            },
             comments,
+            customer_status: self.customer_status?.ok()?,
+            status_change: match self.status_change {
+                Some(status_change) => Some(status_change.ok()?),
+                None => None,
+            },
         })
     }
 }
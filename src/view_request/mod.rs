@@ -14,21 +14,28 @@
 
 mod comments;
 pub mod guaranteed;
+mod network;
 
 use std::fmt::Debug;
 use std::str::FromStr;
 
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
-use chrono_tz::Europe::Kyiv;
+use chrono::{DateTime, FixedOffset, NaiveDate};
 use itertools::Itertools;
 use scraper::{ElementRef, Html};
 use serde::{Deserialize, Serialize};
 
 use crate::data::address::Address;
+use crate::data::aggregation_point::{AggregationPoint, AggregationPointError};
+use crate::data::connection_point::{ConnectionPoint, ConnectionPointError};
+use crate::data::customer_status::{CustomerStatus, CustomerStatusError};
+use crate::data::deadlines::Deadlines;
+use crate::data::equipment::{Equipment, EquipmentError};
 use crate::data::full_comment::{FullComment, FullCommentError};
 use crate::data::internal_status::{InternalStatus, InternalStatusError};
-use crate::data::order_type::{OrderType, OrderTypeError};
+use crate::data::order_type::{OrderCategory, OrderType, OrderTypeError};
+use crate::data::service::{Service, ServiceError};
 use crate::data::status::{Status, StatusError};
+use crate::data::technology::{Technology, TechnologyError};
 use crate::data::time_constrains::{TimeConstrains, TimeConstrainsError};
 use crate::macros::match_and_set;
 use crate::serializable_int_error_kind::SerializableIntErrorKind;
@@ -43,23 +50,38 @@ use crate::traits::prev_element_ref::PrevElementRef;
 pub struct ViewRequest {
     pub order_id:          Option<Result<u32, SerializableIntErrorKind>>,
     pub internal_order_id: Option<Result<u32, SerializableIntErrorKind>>,
-    // TODO: Use "Подтип" to determine active or passive order type
-    /// Currently [`OrderType`] in view request is limited to
-    /// [`OrderType::NetNewUnknown`], [`OrderType::NetRecoveryUnknown`] and
-    /// [`OrderType::NetRelocationUnknown`] instead of their active or passive
-    /// variants. Other types are not affected
+    /// Internal order id of the related order, e.g. the original connection
+    /// of a recovery order
+    pub related_order_id:  Option<Result<u32, SerializableIntErrorKind>>,
+    /// Derived from both "Тип" and "Подтип", so unlike in brief request it's
+    /// known whether the order is active or passive
     pub order_type:        Option<Result<OrderType, OrderTypeError>>,
     // TODO: Rename to creation_datetime
     pub creation_date:     Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
     pub internal_status:   Option<Result<InternalStatus, InternalStatusError>>,
     pub address:           Option<Address>,
+    /// Portal's id of the address (`addrid`), used to fetch staircases
+    pub address_id:        Option<Result<u32, SerializableIntErrorKind>>,
     /// Client's full name (Kyivstar's version)
     pub client:            Option<String>,
     /// Only orders for subscription (connection) to Kyivstar's network contain
     /// service package name
     pub service:           Option<String>,
+    /// Rows of "Услуги" table
+    pub services:          Vec<Result<Service, ServiceError>>,
     /// Client's personal account number
     pub pa:                Option<String>,
+    pub technology:        Option<Result<Technology, TechnologyError>>,
+    /// Telecom cabinet ("ТКД"). `None` if it isn't chosen, which Portal shows
+    /// as `-Все-`
+    pub tkd:               Option<String>,
+    pub aggregation_point: Option<Result<AggregationPoint, AggregationPointError>>,
+    /// "Точки подключения"
+    pub connection_points: Vec<Result<ConnectionPoint, ConnectionPointError>>,
+    /// Equipment and materials issued for the order
+    pub equipment:         Vec<Result<Equipment, EquipmentError>>,
+    /// "Способ включения", like `ВДСС` or `фасад`
+    pub connection_method: Option<String>,
     // TODO: Parse also "Канал подачи заявки", "Код продавца" and "Продавец"
     // TODO: Example at src/tests/assets/view_request/valid/4.html
     /// Name or phone number of person who created order
@@ -73,7 +95,20 @@ pub struct ViewRequest {
     pub phones:            Vec<String>,
     /// Date on which the order is scheduled
     pub assigned_for:      Option<Result<NaiveDate, SerializableParseErrorKind>>,
+    pub deadlines:         Deadlines,
+    /// Connection datetime, i.e. date and the start of time constrains the
+    /// order is scheduled for. `None` if either of them is missing or invalid
+    /// ([`TimeConstrains::starts_at`] errors only on DST transition). Named
+    /// `scheduled_at`, as it's the start of the scheduled visit rather than
+    /// the moment the client gets connected, which isn't known in advance
+    pub scheduled_at:      Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
     pub comments:          Vec<Result<FullComment, FullCommentError>>,
+    /// "Статус у заказчика". Portal usually shows only the [`Status`] there,
+    /// see [`ViewRequest::status_change`] for who and why changed it
+    pub customer_status:   Option<Result<CustomerStatus, CustomerStatusError>>,
+    /// The latest status change made by Kyivstar, parsed from comments.
+    /// `None` if there are none
+    pub status_change:     Option<Result<CustomerStatus, CustomerStatusError>>,
 }
 
 impl PrevElementRef for ViewRequest {}
@@ -115,6 +150,8 @@ impl ViewRequest {
         let mut city = None;
         let mut address_string = None;
         let mut apartment = None;
+        let mut category = None;
+        let mut subtype = None;
 
         for element in elements {
             log::debug!(target: "nsg", "Processing {:?}...", element.id());
@@ -130,7 +167,7 @@ impl ViewRequest {
                         &cur_text
                     );
                     self.internal_order_id =
-                        Some(u32::from_str(&cur_text.replace("Заявка №", "")).map_err(|err| err.kind().clone().into()));
+                        Some(u32::from_str(&cur_text.replace("Заявка №", "")).map_err(|err| (*err.kind()).into()));
                     continue;
                 }
             }
@@ -138,7 +175,27 @@ impl ViewRequest {
             match_and_set!(
                 "наряд:",
                 self.order_id,
-                (|| Some(u32::from_str(&cur_text).map_err(|err| err.kind().clone().into()))),
+                (|| Some(u32::from_str(&cur_text).map_err(|err| (*err.kind()).into()))),
+                self,
+                element,
+                text_getter,
+                is_it,
+                cur_text
+            );
+            match_and_set!(
+                "номер связанной заявки:",
+                self.related_order_id,
+                (|| Some(u32::from_str(cur_text.trim()).map_err(|err| (*err.kind()).into()))),
+                self,
+                element,
+                text_getter,
+                is_it,
+                cur_text
+            );
+            match_and_set!(
+                "тип:",
+                category,
+                (|| Some(OrderCategory::from(cur_text.as_str()))),
                 self,
                 element,
                 text_getter,
@@ -147,8 +204,8 @@ impl ViewRequest {
             );
             match_and_set!(
                 "подтип:",
-                self.order_type,
-                (|| Some(OrderType::from_str(&cur_text))),
+                subtype,
+                (|| Some(cur_text.trim().to_string())),
                 self,
                 element,
                 text_getter,
@@ -158,7 +215,7 @@ impl ViewRequest {
             match_and_set!(
                 "дата создания:",
                 self.creation_date,
-                (|| Some(self.as_datetime(&cur_text))),
+                (|| Some(FullComment::as_datetime(&cur_text))),
                 self,
                 element,
                 text_getter,
@@ -187,8 +244,12 @@ impl ViewRequest {
             );
             match_and_set!(
                 "адрес:",
-                address_string,
-                (|| Some(cur_text)),
+                (|| {
+                    self.address_id = element
+                        .attr("contextitem")
+                        .map(|address_id| u32::from_str(address_id).map_err(|err| (*err.kind()).into()));
+                    address_string = Some(cur_text);
+                }),
                 self,
                 element,
                 text_getter,
@@ -225,6 +286,46 @@ impl ViewRequest {
                 is_it,
                 cur_text
             );
+            match_and_set!(
+                "технология:",
+                self.technology,
+                (|| Some(Technology::from_str(&cur_text))),
+                self,
+                element,
+                text_getter,
+                is_it,
+                cur_text
+            );
+            match_and_set!(
+                "ткд:",
+                self.tkd,
+                (|| Some(cur_text.trim().to_string()).filter(|tkd| !tkd.is_empty() && tkd != "-Все-")),
+                self,
+                element,
+                text_getter,
+                is_it,
+                cur_text
+            );
+            match_and_set!(
+                "точка агрегации:",
+                self.aggregation_point,
+                (|| Some(AggregationPoint::from_str(&cur_text))),
+                self,
+                element,
+                text_getter,
+                is_it,
+                cur_text
+            );
+            match_and_set!(
+                "способ включения:",
+                self.connection_method,
+                (|| Some(cur_text.trim().to_string()).filter(|method| !method.is_empty())),
+                self,
+                element,
+                text_getter,
+                is_it,
+                cur_text
+            );
             match_and_set!(
                 "телефон:",
                 (|| self.phones.push(cur_text)),
@@ -293,14 +394,19 @@ impl ViewRequest {
             );
             match_and_set!(
                 "статус у заказчика:",
-                self.status,
-                (|| Some(Status::from_str(&cur_text))),
+                (|| {
+                    self.status = Some(Status::from_str(&cur_text));
+                    self.customer_status = Some(CustomerStatus::from(&cur_text));
+                }),
                 self,
                 element,
                 text_getter,
                 is_it,
                 cur_text
             );
+            if self.deadlines.collect(element, text_getter) {
+                continue;
+            }
             match_and_set!(
                 "дата подключения:",
                 self.assigned_for,
@@ -321,6 +427,16 @@ impl ViewRequest {
         }
 
         self.set_comments(html_fragment);
+        self.set_network_tables(html_fragment);
+        self.set_services(html_fragment);
+
+        if let Some(subtype) = subtype {
+            self.order_type = Some(OrderType::from_subtype(category, &subtype));
+        }
+
+        if let (Some(Ok(assigned_for)), Some(Ok(time_constrains))) = (&self.assigned_for, &self.time_constrains) {
+            self.scheduled_at = Some(time_constrains.starts_at(*assigned_for));
+        }
 
         if let (Some(city), Some(address_string), Some(apartment)) = (city, address_string, apartment) {
             let mut address_iter = address_string.split(',');
@@ -333,15 +449,4 @@ impl ViewRequest {
             ));
         }
     }
-
-    fn as_datetime(&self, input: &str) -> Result<DateTime<FixedOffset>, SerializableParseErrorKind> {
-        let naive = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S")?;
-        let datetime = naive
-            .and_local_timezone(Kyiv)
-            .earliest()
-            .expect("Never should have gotten a time that doesn't exist in the Kyiv time zone")
-            .fixed_offset();
-
-        Ok(datetime)
-    }
 }
@@ -0,0 +1,52 @@
+use itertools::Itertools;
+use scraper::{ElementRef, Html, Selector};
+
+use super::ViewRequest;
+use crate::data::connection_point::ConnectionPoint;
+use crate::data::equipment::Equipment;
+use crate::data::service::Service;
+
+impl ViewRequest {
+    /// "Точки подключения" and "Оборудование" are nested tables, so they're
+    /// skipped by `collect_fragments`
+    pub fn set_network_tables(&mut self, html_fragment: &Html) {
+        let row_selector = Selector::parse("tr").unwrap();
+
+        if let Some(table) = ViewRequest::nested_table(html_fragment, "точки подключения:") {
+            self.connection_points = table
+                .select(&row_selector)
+                .filter(|row| {
+                    row.first_child()
+                        .and_then(ElementRef::wrap)
+                        .is_some_and(|cell| cell.text().collect::<String>().trim() != "Название")
+                })
+                .map(ConnectionPoint::from)
+                .collect_vec();
+        }
+
+        if let Some(table) = ViewRequest::nested_table(html_fragment, "оборудование:") {
+            self.equipment = table
+                .select(&row_selector)
+                .filter(|row| row.child_elements().next().is_some())
+                .map(Equipment::from)
+                .collect_vec();
+        }
+    }
+
+    pub fn set_services(&mut self, html_fragment: &Html) {
+        let selector = Selector::parse("tr.servicesrow").unwrap();
+
+        self.services = html_fragment.select(&selector).map(Service::from).collect_vec();
+    }
+
+    /// `<td>` next to `<th>` with the given lowercase text
+    fn nested_table<'a>(html_fragment: &'a Html, label: &str) -> Option<ElementRef<'a>> {
+        let th_selector = Selector::parse("th").unwrap();
+
+        html_fragment
+            .select(&th_selector)
+            .find(|th| th.text().collect::<String>().trim().to_lowercase() == label)?
+            .next_siblings()
+            .find_map(ElementRef::wrap)
+    }
+}
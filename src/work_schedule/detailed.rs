@@ -0,0 +1,43 @@
+//! Work schedule enriched with [`ViewRequest`] and/or [`BriefRequest`] of
+//! every [`Order`]. See
+//! [`Nsg::work_schedule_detailed`](crate::Nsg::work_schedule_detailed)
+
+use serde::{Deserialize, Serialize};
+
+use crate::brief_request::BriefRequest;
+use crate::error::NsgResult;
+use crate::view_request::ViewRequest;
+use crate::work_schedule::order::Order;
+
+/// Defines which data pillars are fetched for every [`Order`] and how many
+/// orders are processed at the same time
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct DetailOptions {
+    pub view_request:  bool,
+    pub brief_request: bool,
+    /// Maximum number of orders processed at the same time. `0` is treated as
+    /// `1`
+    pub concurrency:   usize,
+}
+
+impl Default for DetailOptions {
+    /// Fetches only view request, 4 orders at a time
+    fn default() -> Self {
+        DetailOptions {
+            view_request:  true,
+            brief_request: false,
+            concurrency:   4,
+        }
+    }
+}
+
+/// [`Order`] paired with its data pillars. Failure to fetch one of them
+/// doesn't affect other orders
+#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct DetailedOrder {
+    pub order:         Order,
+    /// `None` if view request wasn't requested in [`DetailOptions`]
+    pub view_request:  Option<NsgResult<ViewRequest>>,
+    /// `None` if brief request wasn't requested in [`DetailOptions`]
+    pub brief_request: Option<NsgResult<BriefRequest>>,
+}
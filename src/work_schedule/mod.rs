@@ -12,6 +12,7 @@
 //! println!("Orders: {:#?}", work_schedule.0);
 //! ```
 
+pub mod detailed;
 pub mod order;
 
 use scraper::{Html, Selector};
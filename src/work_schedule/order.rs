@@ -20,12 +20,14 @@
 
 use std::str::FromStr;
 
+use chrono::{DateTime, Duration, FixedOffset};
 use itertools::Itertools;
 use scraper::element_ref::Select;
 use scraper::{ElementRef, Html, Selector};
 use serde::{Deserialize, Serialize};
 
 use crate::data::address::{Address, AddressError};
+use crate::data::full_comment::FullComment;
 use crate::data::internal_status::{InternalStatus, InternalStatusError};
 use crate::data::mdu::{MDUError, MDU};
 use crate::data::order_type::{OrderType, OrderTypeError};
@@ -33,6 +35,7 @@ use crate::data::status::{Status, StatusError};
 use crate::data::time_constrains::{TimeConstrains, TimeConstrainsError};
 use crate::macros::selector;
 use crate::serializable_int_error_kind::SerializableIntErrorKind;
+use crate::serializable_parse_error_kind::SerializableParseErrorKind;
 
 /// Parsed order from work schedule. For detailed information about field refer
 /// to it's documentation. Note that all fields will not fail hard allowing to
@@ -50,12 +53,24 @@ pub struct Order {
     /// Client's personal account number
     pub pa:                Option<String>,
     pub address:           Option<Result<Address, AddressError>>,
+    /// Portal's id of the address (`addrid`)
+    pub address_id:        Option<Result<u32, SerializableIntErrorKind>>,
+    // TODO: Power status of client's equipment isn't parsed. Work schedule
+    // TODO: contains all `span.powerlabel` variants hidden and Portal shows
+    // TODO: one of them with a call that isn't captured yet
     pub mdu:               Option<Result<MDU, MDUError>>,
     pub status:            Option<Result<Status, StatusError>>,
     pub order_type:        Option<Result<OrderType, OrderTypeError>>,
     /// Client's full name (Kyivstar's version)
     pub client:            Option<String>,
     pub internal_status:   Option<Result<InternalStatus, InternalStatusError>>,
+    /// Time of the last update of the order from Kyivstar's side (SBMS)
+    pub last_sbms_update:  Option<Result<DateTime<FixedOffset>, SerializableParseErrorKind>>,
+    /// Customer's timer, i.e. time left until Kyivstar considers order
+    /// overdue. Negative once it's breached
+    pub sbms_duration:     Option<Result<Duration, SerializableIntErrorKind>>,
+    /// Portal marks customer's timer as critical when it's about to breach
+    pub sbms_critical:     Option<bool>,
 }
 
 /// Hence [`Order`] will not fail hard, it's not necessary valid. You can
@@ -71,11 +86,15 @@ pub struct GuaranteedOrder {
     pub phones:            Vec<String>,
     pub pa:                String,
     pub address:           Address,
+    pub address_id:        u32,
     pub mdu:               MDU,
     pub status:            Status,
     pub order_type:        OrderType,
     pub client:            String,
     pub internal_status:   InternalStatus,
+    pub last_sbms_update:  DateTime<FixedOffset>,
+    pub sbms_duration:     Duration,
+    pub sbms_critical:     bool,
 }
 
 impl Order {
@@ -88,11 +107,15 @@ impl Order {
             phones:            self.phones?,
             pa:                self.pa?,
             address:           self.address?.ok()?,
+            address_id:        self.address_id?.ok()?,
             mdu:               self.mdu?.ok()?,
             status:            self.status?.ok()?,
             order_type:        self.order_type?.ok()?,
             client:            self.client?,
             internal_status:   self.internal_status?.ok()?,
+            last_sbms_update:  self.last_sbms_update?.ok()?,
+            sbms_duration:     self.sbms_duration?.ok()?,
+            sbms_critical:     self.sbms_critical?,
         })
     }
 
@@ -105,14 +128,14 @@ impl Order {
             order_index:       selector!(
                 get_and_text "th", next,
                 row, Self::inner_text,
-                (|text: String| Some(u8::from_str(&text.replace('.', "")).map_err(|err| err.kind().clone().into())))
+                (|text: String| Some(u8::from_str(&text.replace('.', "")).map_err(|err| (*err.kind()).into())))
             ),
             order_id:          selector!(
                 get_and_text "td.td_nocontext", next,
                 row, Self::inner_text,
-                (|text: String| Some(u32::from_str(&text.replace("№ наряда: ", "")).map_err(|err| err.kind().clone().into())))
+                (|text: String| Some(u32::from_str(&text.replace("№ наряда: ", "")).map_err(|err| (*err.kind()).into())))
             ),
-            internal_order_id: (|| Some(u32::from_str(rowid?).map_err(|err| err.kind().clone().into())))(),
+            internal_order_id: (|| Some(u32::from_str(rowid?).map_err(|err| (*err.kind()).into())))(),
             time_constrains:   selector!(select "th", row, (|elements: &mut Select| {
                 let from = elements.skip(1).nth(0)?;
                 let to = elements.nth(0)?;
@@ -142,6 +165,11 @@ impl Order {
                 (|text: String| Some(text))
             ),
             address:           selector!(get_as "a.viewAddrLink", next, row, Self::inner_text, Address::from_work_schedule),
+            address_id:        selector!(select "a.viewAddrLink", row, (|elements: &mut Select| {
+                let addrid = elements.next()?.attr("addrid")?;
+
+                Some(u32::from_str(addrid).map_err(|err| (*err.kind()).into()))
+            })),
             mdu:               selector!(get_as "td span.small", next, row, Self::inner_text, MDU::from_work_schedule),
             status:            selector!(get_as "span.networkstatus", next, row, Self::inner_text, Status::from_str),
             order_type:        selector!(select "td", row, (|elements: &mut Select| {
@@ -157,9 +185,53 @@ impl Order {
                 Some(client.trim().to_string())
             })),
             internal_status:   selector!(get_as "td", last, row, Self::inner_text, InternalStatus::from_str),
+            last_sbms_update:  Self::sbms_cell(fragment, rowid, "lastsbmstime")
+                .and_then(|cell| Self::inner_text(&cell))
+                .map(|text| FullComment::as_datetime(text.trim())),
+            sbms_duration:     Self::sbms_cell(fragment, rowid, "sbmsduration")
+                .and_then(|cell| Self::inner_text(&cell))
+                .map(|text| Self::as_duration(text.trim())),
+            sbms_critical:     Self::sbms_cell(fragment, rowid, "sbmsduration")
+                .map(|cell| cell.value().classes().any(|class| class == "critical")),
         }
     }
 
+    /// SBMS cells are located in the second row of the order
+    fn sbms_cell<'a>(fragment: &'a Html, rowid: Option<&str>, class: &str) -> Option<ElementRef<'a>> {
+        let selector = format!(r#"table tr td table tr.requestrow2[rowid="{}"] > td.{class}"#, rowid?);
+
+        fragment.select(&Selector::parse(&selector).ok()?).next()
+    }
+
+    /// Parses timer in `HH:MM:SS` format, where hours can exceed 24 and timer
+    /// can be negative, like `-01:02:03`. Timer that doesn't fit in
+    /// [`Duration`] is [`SerializableIntErrorKind::PosOverflow`] or
+    /// [`SerializableIntErrorKind::NegOverflow`]
+    fn as_duration(input: &str) -> Result<Duration, SerializableIntErrorKind> {
+        let (sign, overflow, input) = match input.strip_prefix('-') {
+            Some(input) => (-1, SerializableIntErrorKind::NegOverflow, input),
+            None => (1, SerializableIntErrorKind::PosOverflow, input),
+        };
+
+        let mut components = input.split(':');
+        let mut next_component = || -> Result<i64, SerializableIntErrorKind> {
+            let component = components.next().ok_or(SerializableIntErrorKind::Empty)?;
+
+            i64::from_str(component).map_err(|err| (*err.kind()).into())
+        };
+
+        let hours = next_component()?;
+        let minutes = next_component()?;
+        let seconds = next_component()?;
+
+        hours
+            .checked_mul(3600)
+            .and_then(|total| total.checked_add(minutes.checked_mul(60)?))
+            .and_then(|total| total.checked_add(seconds))
+            .and_then(|total| Duration::try_seconds(sign * total))
+            .ok_or(overflow)
+    }
+
     fn inner_text(element: &ElementRef<'_>) -> Option<String> {
         let text = element.text().collect_vec().join(" ");
 